@@ -0,0 +1,274 @@
+//! Command-line tool printing the tokens produced by charabia.
+//!
+//! ```text
+//! $ echo "The quick fox" | charabia
+//! lemma    kind             script  language  byte_start  byte_end  char_start  char_end  char_map
+//! "the"    Word             Latin             0           3         0           3
+//! " "      Separator(Soft)  Latin             3           4         3           4
+//! ...
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use charabia::{Language, Script, Token, TokenKind, TokenizerBuilder};
+use clap::{Parser, ValueEnum};
+use fst::Set;
+
+/// Print every token produced by charabia for the provided texts.
+#[derive(Debug, Parser)]
+#[command(name = "charabia", version)]
+struct Opt {
+    /// Files to tokenize, the standard input is read if no file is provided.
+    files: Vec<PathBuf>,
+
+    /// Format of the printed tokens.
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// File containing the stop words, one per line.
+    #[arg(long)]
+    stop_words: Option<PathBuf>,
+
+    /// Languages that can be detected for a script, e.g. `Latin=eng,fra`, can be repeated.
+    #[arg(long, value_parser = parse_allow_list)]
+    allow_list: Vec<(Script, Vec<Language>)>,
+
+    /// Create the char_map of the tokens.
+    #[arg(long)]
+    create_char_map: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One JSON object per token.
+    Jsonl,
+    /// Tab-separated values with a header.
+    Tsv,
+    /// Aligned columns.
+    Table,
+}
+
+const COLUMNS: [&str; 9] = [
+    "lemma",
+    "kind",
+    "script",
+    "language",
+    "byte_start",
+    "byte_end",
+    "char_start",
+    "char_end",
+    "char_map",
+];
+
+fn main() -> ExitCode {
+    let opt = Opt::parse();
+    match run(opt) {
+        Ok(()) => ExitCode::SUCCESS,
+        // the output has been closed, e.g. piped into `head`.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(opt: Opt) -> io::Result<()> {
+    let stop_words = opt.stop_words.as_ref().map(read_stop_words).transpose()?;
+    let allow_list: HashMap<_, _> = opt.allow_list.into_iter().collect();
+
+    let mut builder = TokenizerBuilder::new();
+    builder.create_char_map(opt.create_char_map);
+    if let Some(stop_words) = &stop_words {
+        builder.stop_words(stop_words);
+    }
+    if !allow_list.is_empty() {
+        builder.allow_list(&allow_list);
+    }
+    let tokenizer = builder.build();
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    if opt.format == Format::Tsv {
+        writeln!(output, "{}", COLUMNS.join("\t"))?;
+    }
+
+    let texts: Vec<String> = if opt.files.is_empty() {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        vec![text]
+    } else {
+        opt.files.iter().map(fs::read_to_string).collect::<io::Result<_>>()?
+    };
+
+    for text in &texts {
+        let tokens = tokenizer.tokenize(text);
+        match opt.format {
+            Format::Jsonl => {
+                for token in tokens {
+                    serde_json::to_writer(&mut output, &token)?;
+                    writeln!(output)?;
+                }
+            }
+            Format::Tsv => {
+                for token in tokens {
+                    let row = row(&token).map(|cell| escape(&cell));
+                    writeln!(output, "{}", row.join("\t"))?;
+                }
+            }
+            Format::Table => write_table(&mut output, tokens.map(|token| row(&token)))?,
+        }
+    }
+
+    output.flush()
+}
+
+/// Reads a stop words file containing one word per line.
+fn read_stop_words(path: &PathBuf) -> io::Result<Set<Vec<u8>>> {
+    let content = fs::read_to_string(path)?;
+    let words: BTreeSet<_> =
+        content.lines().map(str::trim).filter(|word| !word.is_empty()).collect();
+    Set::from_iter(words).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Parses an allow list entry formatted as `Script=lang,lang`.
+fn parse_allow_list(entry: &str) -> Result<(Script, Vec<Language>), String> {
+    let (script, languages) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("expected `Script=lang,...`, got `{entry}`"))?;
+
+    let script = match Script::from_name(script.trim()) {
+        Script::Other => return Err(format!("unknown script `{script}`")),
+        script => script,
+    };
+    let languages = languages
+        .split(',')
+        .map(|language| match Language::from_name(language.trim()) {
+            Language::Other => Err(format!("unknown language `{language}`")),
+            language => Ok(language),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((script, languages))
+}
+
+/// Returns the printed cells of a token in the same order as the `COLUMNS`.
+fn row(token: &Token) -> [String; 9] {
+    let kind = match token.kind {
+        TokenKind::Separator(kind) => format!("Separator({kind:?})"),
+        kind => format!("{kind:?}"),
+    };
+    let char_map = token.char_map.as_ref().map_or_else(String::new, |char_map| {
+        char_map.iter().map(|(o, n)| format!("{o}:{n}")).collect::<Vec<_>>().join(",")
+    });
+
+    [
+        token.lemma().to_string(),
+        kind,
+        token.script.name().to_string(),
+        token.language.map_or_else(String::new, |language| language.name().to_string()),
+        token.byte_start.to_string(),
+        token.byte_end.to_string(),
+        token.char_start.to_string(),
+        token.char_end.to_string(),
+        char_map,
+    ]
+}
+
+/// Escapes the characters that would break a TSV row.
+fn escape(cell: &str) -> String {
+    cell.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+/// Writes the rows aligned in columns, lemmas are quoted to show the whitespaces.
+fn write_table(output: &mut impl Write, rows: impl Iterator<Item = [String; 9]>) -> io::Result<()> {
+    let mut rows: Vec<_> = rows
+        .map(|mut row| {
+            row[0] = format!("{:?}", row[0]);
+            row
+        })
+        .collect();
+    rows.insert(0, COLUMNS.map(String::from));
+
+    let mut widths = [0; 9];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in &rows {
+        let line: Vec<_> =
+            row.iter().zip(widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+        writeln!(output, "{}", line.join("  ").trim_end())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use charabia::SeparatorKind;
+
+    use super::*;
+
+    #[test]
+    fn allow_list() {
+        assert_eq!(
+            parse_allow_list("Latin=eng, fra"),
+            Ok((Script::Latin, vec![Language::Eng, Language::Fra]))
+        );
+        assert!(parse_allow_list("Latin").is_err());
+        assert!(parse_allow_list("Klingon=eng").is_err());
+        assert!(parse_allow_list("Latin=xxx").is_err());
+    }
+
+    #[test]
+    fn table() {
+        // the tokens are built by hand, the detected language depends on the enabled features.
+        let tokens = [
+            Token {
+                lemma: "hello".into(),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                byte_end: 5,
+                char_end: 5,
+                ..Default::default()
+            },
+            Token {
+                lemma: " ".into(),
+                kind: TokenKind::Separator(SeparatorKind::Soft),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                byte_start: 5,
+                byte_end: 6,
+                char_start: 5,
+                char_end: 6,
+                ..Default::default()
+            },
+        ];
+        let mut output = Vec::new();
+        write_table(&mut output, tokens.iter().map(row)).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        assert!(lines.next().unwrap().starts_with("lemma    kind"));
+        assert_eq!(
+            lines.next().unwrap(),
+            r#""hello"  Word             Latin             0           5         0           5"#
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            r#"" "      Separator(Soft)  Latin   eng       5           6         5           6"#
+        );
+    }
+
+    #[test]
+    fn tsv_escape() {
+        assert_eq!(escape("a\tb\nc\\"), "a\\tb\\nc\\\\");
+    }
+}
@@ -0,0 +1,48 @@
+use rayon::prelude::*;
+
+use crate::{Token, Tokenizer};
+
+impl<A: AsRef<[u8]> + Sync + Send> Tokenizer<'_, '_, A> {
+    /// Tokenizes the provided documents in parallel on the current rayon thread pool.
+    ///
+    /// Returns the tokens of each document in the same order as the documents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let tokenizer = TokenizerBuilder::default().build();
+    /// let batch = tokenizer.tokenize_batch(&["The quick fox", "jumps over", "the lazy dog"]);
+    ///
+    /// assert_eq!(batch.len(), 3);
+    /// assert_eq!(batch[1][0].lemma(), "jumps");
+    /// ```
+    pub fn tokenize_batch<'o>(&self, documents: &[&'o str]) -> Vec<Vec<Token<'o>>> {
+        documents.par_iter().map(|document| self.tokenize(document).collect()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn keep_documents_order() {
+        let documents: Vec<_> =
+            ["Hello world!", "ภาษาไทยง่ายนิดเดียว", "", "我们常常喜欢吃饭", "안녕하세요"]
+                .into_iter()
+                .cycle()
+                .take(50)
+                .collect();
+
+        let tokenizer = TokenizerBuilder::default().build();
+        let batch = tokenizer.tokenize_batch(&documents);
+
+        assert_eq!(batch.len(), documents.len());
+        for (document, tokens) in documents.iter().zip(batch) {
+            let expected: Vec<_> = document.tokenize().collect();
+            assert_eq!(tokens, expected);
+        }
+    }
+}
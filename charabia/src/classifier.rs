@@ -1,7 +1,10 @@
+use std::ops::RangeInclusive;
+
 use deunicode::deunicode_char;
 use fst::Set;
 
 use crate::segmenter::SegmentedTokenIter;
+use crate::shared::Shared;
 use crate::token::SeparatorKind;
 use crate::{Token, TokenKind};
 
@@ -38,24 +41,61 @@ impl<'o, 'al> SegmentedTokenIter<'o, 'al> {
         self,
         stop_words: Option<&'sw Set<A>>,
     ) -> ClassifiedTokenIter<'o, 'al, 'sw, A> {
-        ClassifiedTokenIter { inner: self, classifier: TokenClassifier::new(stop_words) }
+        self.classify_with_options(stop_words, None)
+    }
+
+    /// Assign to each [`Token`]s a [`TokenKind`] using provided stop words and [`SeparatorPolicy`].
+    ///
+    /// [`TokenKind`]: crate::TokenKind
+    ///
+    /// Any `Token` that is in the stop words [`Set`] is assigned to [`TokenKind::StopWord`],
+    /// the kind of the separators is defined by the `SeparatorPolicy` if any.
+    ///
+    /// [`TokenKind::StopWord`]: crate::TokenKind#StopWord
+    pub fn classify_with_options<'sw, A: AsRef<[u8]>>(
+        self,
+        stop_words: Option<&'sw Set<A>>,
+        separator_policy: Option<&'sw SeparatorPolicy>,
+    ) -> ClassifiedTokenIter<'o, 'al, 'sw, A> {
+        ClassifiedTokenIter {
+            inner: self,
+            classifier: TokenClassifier::new(stop_words, separator_policy),
+        }
+    }
+
+    pub(crate) fn classify_with_shared_options<'sw, A: AsRef<[u8]>>(
+        self,
+        stop_words: Option<Shared<'sw, Set<A>>>,
+        separator_policy: Option<Shared<'sw, SeparatorPolicy>>,
+    ) -> ClassifiedTokenIter<'o, 'al, 'sw, A> {
+        ClassifiedTokenIter {
+            inner: self,
+            classifier: TokenClassifier { stop_words, separator_policy },
+        }
     }
 }
 
 #[derive(Clone)]
 struct TokenClassifier<'sw, A> {
-    stop_words: Option<&'sw Set<A>>,
+    stop_words: Option<Shared<'sw, Set<A>>>,
+    separator_policy: Option<Shared<'sw, SeparatorPolicy>>,
 }
 
 impl Default for TokenClassifier<'_, Vec<u8>> {
     fn default() -> Self {
-        Self { stop_words: None }
+        Self { stop_words: None, separator_policy: None }
     }
 }
 
 impl<'sw, A> TokenClassifier<'sw, A> {
-    pub fn new(stop_words: Option<&'sw Set<A>>) -> Self {
-        Self { stop_words }
+    pub fn new(
+        stop_words: Option<&'sw Set<A>>,
+        separator_policy: Option<&'sw SeparatorPolicy>,
+    ) -> Self {
+        Self {
+            stop_words: stop_words.map(Shared::Borrowed),
+            separator_policy: separator_policy.map(Shared::Borrowed),
+        }
     }
 }
 
@@ -64,12 +104,18 @@ where
     A: AsRef<[u8]>,
 {
     pub fn classify<'o>(&self, mut token: Token<'o>) -> Token<'o> {
+        // tokens already classified by the segmenter, like protected terms, are kept as is.
+        if token.kind != TokenKind::Unknown {
+            return token;
+        }
+
         let lemma = token.lemma();
         let mut is_hard_separator = false;
-        if self.stop_words.map(|stop_words| stop_words.contains(lemma)).unwrap_or(false) {
+        if self.stop_words.as_deref().map(|stop_words| stop_words.contains(lemma)).unwrap_or(false)
+        {
             token.kind = TokenKind::StopWord;
             token
-        } else if lemma.chars().all(|c| match classify_separator(c) {
+        } else if lemma.chars().all(|c| match classify_char(c, self.separator_policy.as_deref()) {
             Some(SeparatorKind::Hard) => {
                 is_hard_separator = true;
                 true
@@ -91,10 +137,101 @@ where
     }
 }
 
+/// Custom classification of characters overriding the default separators.
+///
+/// By default, characters are classified using a built-in list of soft and hard separators,
+/// a `SeparatorPolicy` allows to change the [`SeparatorKind`] of any character or range of characters
+/// or to consider them as part of the words.
+/// The characters marked as word characters are never split from the adjacent words by the segmenters.
+///
+/// When several rules match a character, the last added one wins.
+///
+/// # Example
+///
+/// ```
+/// use charabia::classifier::SeparatorPolicy;
+/// use charabia::{SeparatorKind, TokenizerBuilder};
+///
+/// let mut policy = SeparatorPolicy::new();
+/// policy.word_char('#').word_char('+').word_char('/').separator(';', SeparatorKind::Soft);
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.separator_policy(policy);
+/// let tokenizer = builder.build();
+///
+/// let mut tokens = tokenizer.tokenize("C++; TCP/IP");
+/// assert_eq!(tokens.next().unwrap().lemma(), "c++");
+/// assert_eq!(tokens.next().unwrap().separator_kind(), Some(SeparatorKind::Soft));
+/// assert_eq!(tokens.next().unwrap().separator_kind(), Some(SeparatorKind::Soft));
+/// assert_eq!(tokens.next().unwrap().lemma(), "tcp/ip");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SeparatorPolicy {
+    rules: Vec<(RangeInclusive<char>, Option<SeparatorKind>)>,
+}
+
+impl SeparatorPolicy {
+    /// Create a `SeparatorPolicy` keeping the default classification of every character.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify the provided character as a separator of the provided kind.
+    pub fn separator(&mut self, c: char, kind: SeparatorKind) -> &mut Self {
+        self.separator_range(c..=c, kind)
+    }
+
+    /// Classify the provided range of characters as separators of the provided kind.
+    pub fn separator_range(
+        &mut self,
+        range: RangeInclusive<char>,
+        kind: SeparatorKind,
+    ) -> &mut Self {
+        self.rules.push((range, Some(kind)));
+        self
+    }
+
+    /// Classify the provided character as a part of the words.
+    pub fn word_char(&mut self, c: char) -> &mut Self {
+        self.word_char_range(c..=c)
+    }
+
+    /// Classify the provided range of characters as a part of the words.
+    pub fn word_char_range(&mut self, range: RangeInclusive<char>) -> &mut Self {
+        self.rules.push((range, None));
+        self
+    }
+
+    /// Returns the [`SeparatorKind`] of the provided character or `None` if it is a part of the words.
+    pub fn classify(&self, c: char) -> Option<SeparatorKind> {
+        self.rule(c).unwrap_or_else(|| classify_separator(c))
+    }
+
+    /// Returns true if the provided character has been explicitly marked as a part of the words.
+    pub fn is_word_char(&self, c: char) -> bool {
+        matches!(self.rule(c), Some(None))
+    }
+
+    fn rule(&self, c: char) -> Option<Option<SeparatorKind>> {
+        self.rules.iter().rev().find(|(range, _)| range.contains(&c)).map(|(_, kind)| *kind)
+    }
+}
+
+/// Returns the [`SeparatorKind`] of the provided character using the [`SeparatorPolicy`] if any.
+pub(crate) fn classify_char(c: char, policy: Option<&SeparatorPolicy>) -> Option<SeparatorKind> {
+    match policy {
+        Some(policy) => policy.classify(c),
+        None => classify_separator(c),
+    }
+}
+
 fn classify_separator(c: char) -> Option<SeparatorKind> {
     match deunicode_char(c)?.chars().next()? {
         // Prevent deunicoding cyrillic chars (e.g. ь -> ' is incorrect)
-        _ if ('\u{0410}'..='\u{044f}').contains(&c) => None, // russian cyrillic letters [а-яА-Я]
+        _ if ('\u{0400}'..='\u{04ff}').contains(&c) => None, // cyrillic block, e.g. [а-яА-ЯёїґўѓќҌ]
+        _ if matches!(c, '\u{104A}' | '\u{104B}') => Some(SeparatorKind::Hard), // myanmar sections, [၊။]
+        _ if matches!(c, '\u{0F0B}' | '\u{0F0C}' | '\u{0F14}') => Some(SeparatorKind::Soft), // tibetan tshegs, [་༌༔]
+        _ if matches!(c, '\u{0F08}' | '\u{0F0D}'..='\u{0F12}') => Some(SeparatorKind::Hard), // tibetan shads, [༈།-༒]
         c if c.is_whitespace() => Some(SeparatorKind::Soft), // whitespaces
         '-' | '_' | '\'' | ':' | '/' | '\\' | '@' | '"' | '+' | '~' | '=' | '^' | '*' | '#' => {
             Some(SeparatorKind::Soft)
@@ -151,7 +288,7 @@ mod test {
     #[test]
     fn stop_words() {
         let stop_words = Set::from_iter(["the"].iter()).unwrap();
-        let classifier = TokenClassifier::new(Some(&stop_words));
+        let classifier = TokenClassifier::new(Some(&stop_words), None);
 
         let token =
             classifier.classify(Token { lemma: Cow::Borrowed("the"), ..Default::default() });
@@ -165,4 +302,31 @@ mod test {
             classifier.classify(Token { lemma: Cow::Borrowed("foobar"), ..Default::default() });
         assert!(token.is_word());
     }
+
+    #[test]
+    fn separator_policy() {
+        let mut policy = SeparatorPolicy::new();
+        policy
+            .word_char('#')
+            .separator(';', SeparatorKind::Soft)
+            .separator_range('a'..='c', SeparatorKind::Hard)
+            .word_char('b');
+        let classifier = TokenClassifier::<Vec<u8>>::new(None, Some(&policy));
+
+        let token = classifier.classify(Token { lemma: Cow::Borrowed("#"), ..Default::default() });
+        assert!(token.is_word());
+
+        let token = classifier.classify(Token { lemma: Cow::Borrowed(" ;"), ..Default::default() });
+        assert_eq!(token.separator_kind(), Some(SeparatorKind::Soft));
+
+        let token = classifier.classify(Token { lemma: Cow::Borrowed("ac"), ..Default::default() });
+        assert_eq!(token.separator_kind(), Some(SeparatorKind::Hard));
+
+        let token = classifier.classify(Token { lemma: Cow::Borrowed("ab"), ..Default::default() });
+        assert!(token.is_word());
+
+        // characters without rules keep their default kind.
+        let token = classifier.classify(Token { lemma: Cow::Borrowed("."), ..Default::default() });
+        assert_eq!(token.separator_kind(), Some(SeparatorKind::Hard));
+    }
 }
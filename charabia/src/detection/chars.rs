@@ -162,6 +162,16 @@ pub(crate) fn is_khmer(ch: char) -> bool {
     matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
 }
 
+// Based on: https://en.wikipedia.org/wiki/Lao_(Unicode_block)
+pub(crate) fn is_lao(ch: char) -> bool {
+    matches!(ch, '\u{0E80}'..='\u{0EFF}')
+}
+
+// Based on: https://en.wikipedia.org/wiki/Tibetan_(Unicode_block)
+pub(crate) fn is_tibetan(ch: char) -> bool {
+    matches!(ch, '\u{0F00}'..='\u{0FFF}')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +290,20 @@ mod tests {
         assert!(!is_oriya('౿'));
     }
 
+    #[test]
+    fn test_is_lao() {
+        assert!(is_lao('ກ'));
+        assert!(is_lao('ໟ'));
+        assert!(!is_lao('ก'));
+    }
+
+    #[test]
+    fn test_is_tibetan() {
+        assert!(is_tibetan('ཀ'));
+        assert!(is_tibetan('་'));
+        assert!(!is_tibetan('ก'));
+    }
+
     #[test]
     fn test_is_hebrew() {
         assert!(is_hebrew('א'));
@@ -32,9 +32,15 @@ impl<'o, 'al> StrDetection<'o, 'al> {
     }
 
     /// detect script with whatlang,
-    /// if no script is detected, return Script::Other
+    /// if whatlang doesn't detect any script, return the script of the first character having one,
+    /// otherwise return Script::Other
     fn detect_script(text: &str) -> Script {
-        whatlang::detect_script(text).map(Script::from).unwrap_or_default()
+        whatlang::detect_script(text).map(Script::from).unwrap_or_else(|| {
+            text.chars()
+                .map(Script::from)
+                .find(|script| *script != Script::Other)
+                .unwrap_or_default()
+        })
     }
 
     /// detect lang with whatlang
@@ -1,5 +1,7 @@
 use core::str::FromStr;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use super::chars;
 
 macro_rules! make_language {
@@ -120,10 +122,11 @@ make_language! {
 }
 
 macro_rules! make_script {
-    ($($script:tt), +) => {
+    ($($script:tt), +; $($other_script:tt), +) => {
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
         pub enum Script {
             $($script),+,
+            $($other_script),+,
             Cj,
             Other,
         }
@@ -144,13 +147,18 @@ macro_rules! make_script {
             pub fn name(&self) -> &'static str {
                 match self {
                     $(Script::$script => whatlang::Script::$script.name()), +,
+                    $(Script::$other_script => stringify!($other_script)), +,
                     Script::Cj => whatlang::Script::Mandarin.name(),
                     _other => "other",
                 }
             }
 
             pub fn from_name<S: AsRef<str>>(code: S) -> Script {
-                whatlang::Script::from_str(code.as_ref()).map(Script::from).unwrap_or_default()
+                let code = code.as_ref();
+                whatlang::Script::from_str(code).map(Script::from).unwrap_or_else(|_| match code {
+                    $(stringify!($other_script) => Script::$other_script), +,
+                    _other => Script::Other,
+                })
             }
         }
     };
@@ -178,7 +186,10 @@ make_script! {
     Sinhala,
     Tamil,
     Telugu,
-    Thai
+    Thai;
+    // scripts unknown to whatlang
+    Lao,
+    Tibetan
 }
 
 impl From<char> for Script {
@@ -230,6 +241,10 @@ impl From<char> for Script {
             Script::Sinhala
         } else if chars::is_khmer(other) {
             Script::Khmer
+        } else if chars::is_lao(other) {
+            Script::Lao
+        } else if chars::is_tibetan(other) {
+            Script::Tibetan
         } else {
             Script::Other
         }
@@ -242,6 +257,34 @@ impl Default for Script {
     }
 }
 
+/// Serializes the `Language` as its [`name`](Language::name).
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes the `Language` from its name, see [`from_name`](Language::from_name).
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Language::from_name)
+    }
+}
+
+/// Serializes the `Script` as its [`name`](Script::name).
+impl Serialize for Script {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes the `Script` from its name, see [`from_name`](Script::from_name).
+impl<'de> Deserialize<'de> for Script {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Script::from_name)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{Language, Script};
@@ -262,5 +305,19 @@ mod test {
         assert_eq!(Script::from_name("Latin"), Script::Latin);
         assert_eq!(Script::Cj.name(), "Mandarin");
         assert_eq!(Script::from_name("Mandarin"), Script::Cj);
+        assert_eq!(Script::Lao.name(), "Lao");
+        assert_eq!(Script::from_name("Lao"), Script::Lao);
+        assert_eq!(Script::Tibetan.name(), "Tibetan");
+        assert_eq!(Script::from_name("Tibetan"), Script::Tibetan);
+    }
+
+    #[test]
+    fn serde_names() {
+        assert_eq!(serde_json::to_string(&Language::Jpn).unwrap(), r#""jpn""#);
+        assert_eq!(serde_json::from_str::<Language>(r#""jpn""#).unwrap(), Language::Jpn);
+        assert_eq!(serde_json::from_str::<Language>(r#""unknown""#).unwrap(), Language::Other);
+        assert_eq!(serde_json::to_string(&Script::Cj).unwrap(), r#""Mandarin""#);
+        assert_eq!(serde_json::from_str::<Script>(r#""Mandarin""#).unwrap(), Script::Cj);
+        assert_eq!(serde_json::from_str::<Script>(r#""other""#).unwrap(), Script::Other);
     }
 }
@@ -0,0 +1,292 @@
+//! Highlighting and cropping of an original text using its [`Token`]s.
+//!
+//! # Example
+//!
+//! ```
+//! use charabia::highlight::{Highlighter, MatchingWords};
+//! use charabia::TokenizerBuilder;
+//!
+//! let text = "Le Pâtissier prépare des pâtisseries à Paris.";
+//!
+//! let mut builder = TokenizerBuilder::default();
+//! builder.create_char_map(true);
+//! let tokenizer = builder.build();
+//! let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+//!
+//! let mut matching_words = MatchingWords::new();
+//! matching_words.insert("patissier", false).insert("pari", true);
+//!
+//! let highlighter = Highlighter::new(text, &tokens);
+//! assert_eq!(
+//!     highlighter.highlight(&matching_words, "<em>", "</em>"),
+//!     "Le <em>Pâtissier</em> prépare des pâtisseries à <em>Pari</em>s."
+//! );
+//! ```
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::Token;
+
+/// Normalized lemmas to highlight, usually coming from a tokenized query.
+#[derive(Debug, Clone, Default)]
+pub struct MatchingWords {
+    words: Vec<(String, bool)>,
+}
+
+impl MatchingWords {
+    /// Create an empty list of matching words.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a lemma to match.
+    ///
+    /// # Arguments
+    ///
+    /// * `lemma` - the normalized lemma to match.
+    /// * `is_prefix` - a `bool` that indicates whether the lemma also matches the words starting with it.
+    pub fn insert(&mut self, lemma: impl Into<String>, is_prefix: bool) -> &mut Self {
+        self.words.push((lemma.into(), is_prefix));
+        self
+    }
+
+    /// Returns the index of the matching word that matches the provided lemma
+    /// with the number of matched bytes of the lemma.
+    ///
+    /// An exact match is preferred over a prefix match, then the longest prefix is preferred.
+    fn matching(&self, lemma: &str) -> Option<(usize, usize)> {
+        self.words
+            .iter()
+            .enumerate()
+            .filter(|(_, (word, is_prefix))| match is_prefix {
+                true => lemma.starts_with(word.as_str()),
+                false => lemma == word,
+            })
+            .max_by_key(|(_, (word, _))| word.len())
+            .map(|(index, (word, _))| (index, word.len()))
+    }
+
+    /// Same as [`matching`](Self::matching) for the lemma of the token, then for its alternatives.
+    ///
+    /// The number of matched bytes is `None` when only an alternative matches,
+    /// alternatives are not covered by the `char_map` so the whole word is matched.
+    fn matching_token(&self, token: &Token) -> Option<(usize, Option<usize>)> {
+        match self.matching(token.lemma()) {
+            Some((index, matched_len)) => Some((index, Some(matched_len))),
+            None => token
+                .alternatives
+                .iter()
+                .find_map(|alternative| self.matching(alternative))
+                .map(|(index, _)| (index, None)),
+        }
+    }
+}
+
+/// Structure computing the parts of an original text to highlight or to crop.
+///
+/// The tokens must come from the tokenization of the original text,
+/// they should be created with a `char_map` to highlight accurately the prefix matches,
+/// see [`TokenizerBuilder::create_char_map`](crate::TokenizerBuilder::create_char_map).
+pub struct Highlighter<'o, 't> {
+    text: &'o str,
+    tokens: &'t [Token<'o>],
+}
+
+impl<'o, 't> Highlighter<'o, 't> {
+    pub fn new(text: &'o str, tokens: &'t [Token<'o>]) -> Self {
+        Self { text, tokens }
+    }
+
+    /// Returns the byte ranges of the original text matching the provided words.
+    ///
+    /// The whole word is marked for an exact match,
+    /// only the part of the word corresponding to the matching word is marked for a prefix match.
+    /// A word also matches through its [`Token::alternatives`], like its unstemmed lemma,
+    /// the whole word is then marked.
+    pub fn matches(&self, matching_words: &MatchingWords) -> Vec<Range<usize>> {
+        self.tokens
+            .iter()
+            .filter(|token| !token.is_separator())
+            .filter_map(|token| {
+                let end = match matching_words.matching_token(token)? {
+                    (_, Some(matched_len)) if matched_len != token.byte_len() => {
+                        let (_, original_len) = token.original_lengths(matched_len);
+                        self.ceil_char_boundary(
+                            (token.byte_start + original_len).min(token.byte_end),
+                        )
+                    }
+                    _ => token.byte_end,
+                };
+                Some(token.byte_start..end)
+            })
+            .collect()
+    }
+
+    /// Returns the original text with the matches surrounded by `pre` and `post`.
+    pub fn highlight(&self, matching_words: &MatchingWords, pre: &str, post: &str) -> String {
+        let mut highlighted = String::with_capacity(self.text.len());
+        let mut last_end = 0;
+        for Range { start, end } in self.matches(matching_words) {
+            // skip the matches overlapping the previous one, like the parts of a matching compound word.
+            if start < last_end {
+                continue;
+            }
+            highlighted.push_str(&self.text[last_end..start]);
+            highlighted.push_str(pre);
+            highlighted.push_str(&self.text[start..end]);
+            highlighted.push_str(post);
+            last_end = end;
+        }
+        highlighted.push_str(&self.text[last_end..]);
+
+        highlighted
+    }
+
+    /// Returns the byte range of the original text containing the window of `crop_size` words
+    /// that contains the most matching words.
+    ///
+    /// Windows are ranked by the number of different matching words they contain,
+    /// then by the total number of matches; the first window is returned on equality.
+    /// The range starts at the first word of the window and ends at its last word.
+    pub fn crop(&self, matching_words: &MatchingWords, crop_size: usize) -> Range<usize> {
+        let mut words: Vec<(&Token, Option<usize>)> = Vec::new();
+        for token in self.tokens.iter().filter(|token| !token.is_separator()) {
+            let matching = matching_words.matching_token(token).map(|(index, _)| index);
+            match words.last_mut() {
+                // a compound word matches if one of its parts matches.
+                Some((_, word_matching)) if token.is_subword() => {
+                    *word_matching = word_matching.or(matching);
+                }
+                _ => words.push((token, matching)),
+            }
+        }
+
+        if crop_size == 0 || words.is_empty() {
+            return 0..0;
+        }
+
+        let crop_size = crop_size.min(words.len());
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        let mut matches = 0;
+        let mut best = (0, 0, 0);
+
+        for (index, (_, matching)) in words.iter().enumerate() {
+            if let Some(matching) = matching {
+                *counts.entry(*matching).or_default() += 1;
+                matches += 1;
+            }
+
+            // remove the word leaving the window.
+            if index >= crop_size {
+                if let Some(matching) = words[index - crop_size].1 {
+                    let count = counts.get_mut(&matching).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        counts.remove(&matching);
+                    }
+                    matches -= 1;
+                }
+            }
+
+            if index + 1 >= crop_size {
+                let start = index + 1 - crop_size;
+                if (counts.len(), matches) > (best.1, best.2) {
+                    best = (start, counts.len(), matches);
+                }
+            }
+        }
+
+        let (start, _, _) = best;
+        words[start].0.byte_start..words[start + crop_size - 1].0.byte_end
+    }
+
+    fn ceil_char_boundary(&self, mut index: usize) -> usize {
+        while !self.text.is_char_boundary(index) {
+            index += 1;
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TokenizerBuilder;
+
+    fn tokens(text: &str) -> Vec<Token<'static>> {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+        tokenizer.tokenize(text).map(Token::into_owned).collect()
+    }
+
+    #[test]
+    fn exact_and_prefix_matches() {
+        let text = "Ĉu vi ŝatas ŝokoladon? Ŝokolado!";
+        let tokens = tokens(text);
+        let highlighter = Highlighter::new(text, &tokens);
+
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("vi", false).insert("soko", true).insert("sokolado", false);
+
+        let matches: Vec<_> =
+            highlighter.matches(&matching_words).into_iter().map(|range| &text[range]).collect();
+        assert_eq!(matches, ["vi", "ŝoko", "Ŝokolado"]);
+
+        // a non prefix word only matches whole words.
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("v", false);
+        assert!(highlighter.matches(&matching_words).is_empty());
+    }
+
+    #[test]
+    fn highlight() {
+        let text = "The quick brown fox";
+        let tokens = tokens(text);
+        let highlighter = Highlighter::new(text, &tokens);
+
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("qu", true).insert("fox", false);
+        assert_eq!(highlighter.highlight(&matching_words, "[", "]"), "The [qu]ick brown [fox]");
+    }
+
+    #[test]
+    fn crop() {
+        let text = "one two three four. five six, seven eight nine ten eleven twelve";
+        let tokens = tokens(text);
+        let highlighter = Highlighter::new(text, &tokens);
+
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("six", false).insert("eight", false).insert("two", false);
+        // "six seven eight" contains two different matches.
+        assert_eq!(&text[highlighter.crop(&matching_words, 3)], "six, seven eight");
+        assert_eq!(&text[highlighter.crop(&matching_words, 1)], "two");
+
+        // without matches, the beginning of the text is returned.
+        assert_eq!(&text[highlighter.crop(&MatchingWords::new(), 2)], "one two");
+        // the crop size is bounded by the number of words.
+        assert_eq!(highlighter.crop(&matching_words, 100), 0..text.len());
+        assert_eq!(highlighter.crop(&matching_words, 0), 0..0);
+    }
+
+    #[test]
+    fn alternatives() {
+        let text = "Die Mühle";
+        let mut tokens = tokens(text);
+        // like the form without umlauts added by the German expansion.
+        tokens[2].lemma = "muehle".into();
+        tokens[2].alternatives.push("muhle".to_string());
+        let highlighter = Highlighter::new(text, &tokens);
+
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("muhle", false);
+        assert_eq!(highlighter.highlight(&matching_words, "[", "]"), "Die [Mühle]");
+
+        // a prefix matching an alternative marks the whole word.
+        let mut matching_words = MatchingWords::new();
+        matching_words.insert("muh", true);
+        assert_eq!(highlighter.highlight(&matching_words, "[", "]"), "Die [Mühle]");
+        assert_eq!(&text[highlighter.crop(&matching_words, 1)], "Mühle");
+    }
+}
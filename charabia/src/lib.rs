@@ -43,15 +43,26 @@
 //! Theses features are listed in charabia's `cargo.toml` and can be deactivated via [dependency features](https://doc.rust-lang.org/cargo/reference/features.html#dependency-features).
 
 pub mod classifier;
+pub mod highlight;
 pub mod normalizer;
 pub mod segmenter;
+pub mod synonym;
 
+#[cfg(feature = "rayon")]
+mod batch;
 mod detection;
+mod position;
+mod query;
+mod shared;
+mod stream;
 mod token;
 mod tokenizer;
 
 pub use detection::{Language, Script};
+pub use position::{PositionedTokenIter, DEFAULT_HARD_SEPARATOR_GAP};
+pub use query::{Query, QueryTerm};
 pub use segmenter::Segment;
+pub use stream::StreamingTokenIter;
 pub use token::{SeparatorKind, Token, TokenKind};
 
 pub use crate::tokenizer::{ReconstructedTokenIter, Tokenize, Tokenizer, TokenizerBuilder};
@@ -1,4 +1,4 @@
-use super::{CharNormalizer, CharOrStr};
+use super::{CharNormalizer, CharOrStr, NormalizerOption};
 use crate::{Script, Token};
 
 /// A global [`Normalizer`] removing the arabic Tatweel ('ـ') characters.
@@ -14,6 +14,10 @@ impl CharNormalizer for ArabicNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.script == Script::Arabic && token.lemma().chars().any(is_tatweel)
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.remove_tatweel && CharNormalizer::should_normalize(self, token)
+    }
 }
 
 fn is_tatweel(c: char) -> bool {
@@ -1,6 +1,6 @@
 use pinyin::ToPinyin;
 
-use super::CharNormalizer;
+use super::{CharNormalizer, Normalizer, NormalizerOption};
 use crate::detection::{Language, Script};
 use crate::normalizer::CharOrStr;
 use crate::Token;
@@ -9,17 +9,45 @@ use crate::Token;
 /// 1. convert Z, Simplified, Semantic, Old, and Wrong variants
 /// 2. converting them into Pinyin characters
 ///
+/// Each step can be disabled using [`NormalizerOption::kvariants`] and [`NormalizerOption::pinyin`].
+///
 /// This Normalizer uses [`pinyin`] internally to normalize the provided token.
 pub struct ChineseNormalizer;
 
-impl CharNormalizer for ChineseNormalizer {
+impl Normalizer for ChineseNormalizer {
+    fn normalize<'o>(&self, token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        let normalizer =
+            ChineseCharNormalizer { kvariants: options.kvariants, pinyin: options.pinyin };
+        normalizer.normalize(token, options)
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cj && matches!(token.language, None | Some(Language::Cmn))
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        (options.kvariants || options.pinyin) && self.should_normalize(token)
+    }
+}
+
+/// [`CharNormalizer`] applying the Chinese normalization steps enabled in the [`NormalizerOption`].
+struct ChineseCharNormalizer {
+    kvariants: bool,
+    pinyin: bool,
+}
+
+impl CharNormalizer for ChineseCharNormalizer {
     fn normalize_char(&self, c: char) -> Option<CharOrStr> {
         // Normalize Z, Simplified, Semantic, Old, and Wrong variants
         let kvariant = match irg_kvariants::KVARIANTS.get(&c) {
-            Some(kvariant) => kvariant.destination_ideograph,
-            None => c,
+            Some(kvariant) if self.kvariants => kvariant.destination_ideograph,
+            _ => c,
         };
 
+        if !self.pinyin {
+            return Some(kvariant.into());
+        }
+
         // Normalize to Pinyin
         // If we don't manage to convert the kvariant, we try to convert the original character.
         // If none of them are converted, we return the kvariant.
@@ -34,7 +62,11 @@ impl CharNormalizer for ChineseNormalizer {
     }
 
     fn should_normalize(&self, token: &Token) -> bool {
-        token.script == Script::Cj && matches!(token.language, None | Some(Language::Cmn))
+        Normalizer::should_normalize(&ChineseNormalizer, token)
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        Normalizer::should_normalize_with_options(&ChineseNormalizer, token, options)
     }
 }
 
@@ -145,4 +177,25 @@ mod test {
     }
 
     test_normalizer!(ChineseNormalizer, tokens(), normalizer_result(), normalized_tokens());
+
+    #[test]
+    fn disabled_steps() {
+        let token = || Token {
+            lemma: Owned("澚䀾".to_string()),
+            char_end: 2,
+            byte_end: 6,
+            script: Script::Cj,
+            language: Some(Language::Cmn),
+            ..Default::default()
+        };
+
+        let options = NormalizerOption { pinyin: false, ..Default::default() };
+        assert_eq!(ChineseNormalizer.normalize(token(), options).lemma(), "澳䁈");
+
+        let options = NormalizerOption { kvariants: false, ..Default::default() };
+        assert_eq!(ChineseNormalizer.normalize(token(), options).lemma(), "yudǔ");
+
+        let options = NormalizerOption { kvariants: false, pinyin: false, ..Default::default() };
+        assert!(!ChineseNormalizer.should_normalize_with_options(&token(), options));
+    }
 }
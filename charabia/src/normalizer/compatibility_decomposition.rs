@@ -2,7 +2,7 @@ use std::iter::once;
 
 use unicode_normalization::{is_nfkd_quick, UnicodeNormalization};
 
-use super::{CharNormalizer, CharOrStr};
+use super::{CharNormalizer, CharOrStr, NormalizerOption};
 use crate::Token;
 
 /// A global [`Normalizer`] normalizing to the Unicode Normalization Form KD.
@@ -37,6 +37,10 @@ impl CharNormalizer for CompatibilityDecompositionNormalizer {
                 unicode_normalization::IsNormalized::Yes
             ))
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.compatibility_decomposition && CharNormalizer::should_normalize(self, token)
+    }
 }
 
 // Test the normalizer:
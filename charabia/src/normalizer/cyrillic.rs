@@ -0,0 +1,441 @@
+use unicode_normalization::char::compose;
+
+use super::{replace_chars, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+const GRAVE: char = '\u{0300}';
+const ACUTE: char = '\u{0301}';
+const BREVE: char = '\u{0306}';
+const DIAERESIS: char = '\u{0308}';
+
+/// Fold the Cyrillic letters that users commonly mix up, depending on the [`Language`] of the `Token`:
+/// - Russian: `ё` becomes `е`,
+/// - Ukrainian: `ї` becomes `і` and `ґ` becomes `г`,
+/// - Belarusian, Bulgarian, Serbian and Macedonian: only the common rules are applied.
+///
+/// For every language, the stress marks (combining acute and grave accents) are removed,
+/// so `ѐ` and `ѝ` become `е` and `и`,
+/// and the letters split by the compatibility decomposition, like `й`, `ў`, `ѓ` or `ќ`, are composed back.
+///
+/// These foldings don't conflict, so all of them are applied when the language is unknown.
+pub struct CyrillicNormalizer;
+
+impl Normalizer for CyrillicNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        let foldings = Foldings::from(token.language);
+
+        // normalized string of each char of the lemma.
+        let mut normalized: Vec<(usize, String)> = Vec::new();
+        let mut last_letter = None;
+        for c in token.lemma().chars() {
+            let s = match c {
+                GRAVE | ACUTE | BREVE | DIAERESIS => {
+                    match last_letter
+                        .and_then(|index| fold_mark(&mut normalized, index, c, foldings))
+                    {
+                        Some(s) => s,
+                        None => c.to_string(),
+                    }
+                }
+                c => {
+                    last_letter = Some(normalized.len());
+                    fold_letter(c, foldings).to_string()
+                }
+            };
+            normalized.push((c.len_utf8(), s));
+        }
+
+        replace_chars(&mut token, normalized, options);
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cyrillic && token.lemma().chars().any(is_foldable)
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.fold_cyrillic && self.should_normalize(token)
+    }
+
+    fn needs_language(&self, options: NormalizerOption) -> bool {
+        options.fold_cyrillic
+    }
+}
+
+/// Foldings enabled for a language.
+#[derive(Clone, Copy)]
+struct Foldings {
+    /// `ё` -> `е`
+    yo: bool,
+    /// `ї` -> `і`
+    yi: bool,
+    /// `ґ` -> `г`
+    ghe: bool,
+}
+
+impl From<Option<Language>> for Foldings {
+    fn from(language: Option<Language>) -> Self {
+        match language {
+            Some(Language::Rus) => Self { yo: true, yi: false, ghe: false },
+            Some(Language::Ukr) => Self { yo: false, yi: true, ghe: true },
+            Some(Language::Bel | Language::Bul | Language::Srp | Language::Mkd) => {
+                Self { yo: false, yi: false, ghe: false }
+            }
+            _ => Self { yo: true, yi: true, ghe: true },
+        }
+    }
+}
+
+fn fold_letter(c: char, foldings: Foldings) -> char {
+    match c {
+        'ё' if foldings.yo => 'е',
+        'Ё' if foldings.yo => 'Е',
+        'ї' if foldings.yi => 'і',
+        'Ї' if foldings.yi => 'І',
+        'ґ' if foldings.ghe => 'г',
+        'Ґ' if foldings.ghe => 'Г',
+        'ѐ' => 'е',
+        'Ѐ' => 'Е',
+        'ѝ' => 'и',
+        'Ѝ' => 'И',
+        c => c,
+    }
+}
+
+/// Returns the normalization of a combining mark following the letter at `index`,
+/// the letter is replaced if the mark is composed with it.
+fn fold_mark(
+    normalized: &mut [(usize, String)],
+    index: usize,
+    mark: char,
+    foldings: Foldings,
+) -> Option<String> {
+    let (_, letter) = &mut normalized[index];
+    let base = letter.chars().next_back()?;
+    let remove = match (base, mark) {
+        // stress marks, except for the Macedonian letters `ѓ` and `ќ`.
+        ('г' | 'Г' | 'к' | 'К', ACUTE) => false,
+        (_, GRAVE | ACUTE) => true,
+        ('е' | 'Е', DIAERESIS) => foldings.yo,
+        ('і' | 'І', DIAERESIS) => foldings.yi,
+        _ => false,
+    };
+
+    if remove {
+        return Some(String::new());
+    }
+
+    let composed = compose(base, mark).filter(|c| Script::from(*c) == Script::Cyrillic)?;
+    letter.pop();
+    letter.push(composed);
+    Some(String::new())
+}
+
+fn is_foldable(c: char) -> bool {
+    matches!(
+        c,
+        GRAVE
+            | ACUTE
+            | BREVE
+            | DIAERESIS
+            | 'ё'
+            | 'Ё'
+            | 'ї'
+            | 'Ї'
+            | 'ґ'
+            | 'Ґ'
+            | 'ѐ'
+            | 'Ѐ'
+            | 'ѝ'
+            | 'Ѝ'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::{Language, TokenKind};
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Ёжик".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("молоко́".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Україна".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Ukr),
+                ..Default::default()
+            },
+            // ё is kept in Belarusian.
+            Token {
+                lemma: Owned("ёсць".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Bel),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ѝ".to_string()),
+                char_end: 1,
+                byte_end: 2,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Bul),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ѓубре".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Mkd),
+                ..Default::default()
+            },
+            // the language is unknown.
+            Token {
+                lemma: Owned("їжак".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Ежик".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("молоко".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Украіна".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 7]),
+                script: Script::Cyrillic,
+                language: Some(Language::Ukr),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ёсць".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 4]),
+                script: Script::Cyrillic,
+                language: Some(Language::Bel),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("и".to_string()),
+                char_end: 1,
+                byte_end: 2,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Bul),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ѓубре".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Mkd),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("іжак".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 4]),
+                script: Script::Cyrillic,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("ежик".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("молоко".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("украіна".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 7]),
+                script: Script::Cyrillic,
+                language: Some(Language::Ukr),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ёсць".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 4]),
+                script: Script::Cyrillic,
+                language: Some(Language::Bel),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("и".to_string()),
+                char_end: 1,
+                byte_end: 2,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Bul),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("ѓубре".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 5]),
+                script: Script::Cyrillic,
+                language: Some(Language::Mkd),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("іжак".to_string()),
+                char_end: 4,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2); 4]),
+                script: Script::Cyrillic,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // the language dependent normalizers would alter the Russian words of the global normalization.
+    fn options() -> NormalizerOption {
+        NormalizerOption {
+            #[cfg(feature = "lemmatization")]
+            lemmatization: false,
+            #[cfg(feature = "stemming")]
+            stemming: false,
+            ..Default::default()
+        }
+    }
+
+    test_normalizer!(
+        CyrillicNormalizer,
+        tokens(),
+        normalizer_result(),
+        normalized_tokens(),
+        options()
+    );
+
+    #[test]
+    fn decomposed_letters() {
+        let options = NormalizerOption { create_char_map: true, ..Default::default() };
+        // й and ў are letters split by the compatibility decomposition.
+        let token = Token {
+            lemma: Owned("мои\u{306} у\u{306}се\u{308}".to_string()),
+            char_map: Some(vec![(2, 2), (2, 2), (2, 4), (1, 1), (2, 4), (2, 2), (2, 4)]),
+            script: Script::Cyrillic,
+            language: Some(Language::Bel),
+            ..Default::default()
+        };
+        let token = CyrillicNormalizer.normalize(token, options);
+        assert_eq!(token.lemma(), "мой ўсё");
+        assert_eq!(
+            token.char_map,
+            Some(vec![(2, 2), (2, 2), (2, 2), (1, 1), (2, 2), (2, 2), (2, 2)])
+        );
+
+        let options = NormalizerOption { fold_cyrillic: false, ..Default::default() };
+        assert!(!CyrillicNormalizer.should_normalize_with_options(&token, options));
+    }
+
+    #[test]
+    fn tokenize_detects_language() {
+        use crate::Tokenize;
+
+        let words: Vec<_> = "Ёлка стаіць каля хаты, беларуская мова вельмі прыгожая"
+            .tokenize()
+            .filter(|token| token.is_word())
+            .map(|token| (token.lemma().to_string(), token.language))
+            .collect();
+        assert_eq!(words[0], ("ёлка".to_string(), Some(Language::Bel)));
+
+        let words: Vec<_> = "Ёлка стоит возле дома, русский язык очень красивый"
+            .tokenize()
+            .filter(|token| token.is_word())
+            .map(|token| (token.lemma().to_string(), token.language))
+            .collect();
+        assert_eq!(words[0], ("елка".to_string(), Some(Language::Rus)));
+    }
+}
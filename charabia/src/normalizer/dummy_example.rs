@@ -45,7 +45,7 @@ impl CharNormalizer for DummyNormalizer {
 // Include the newly implemented Normalizer in the tokenization pipeline:
 //     - change the name of the file `dummy_example.rs` to `dummy.rs`
 //     - import module by adding `mod dummy;` (filename) in `normalizer/mod.rs`
-//     - Add Normalizer in `NORMALIZERS` in `normalizer/mod.rs`
+//     - Add Normalizer in the default `NormalizerPipeline` in `normalizer/mod.rs`
 //     - check if it didn't break any test or benhchmark
 
 // Test the normalizer:
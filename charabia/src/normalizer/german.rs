@@ -0,0 +1,250 @@
+use super::nonspacing_mark::is_nonspacing_mark;
+use super::{replace_chars, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+const DIAERESIS: char = '\u{0308}';
+
+/// Normalize German words by expanding the `ß` into `ss` and the umlauts into their two-letter forms:
+/// `ä` becomes `ae`, `ö` becomes `oe` and `ü` becomes `ue`.
+///
+/// The expanded form becomes the lemma, so that "Straße" matches "Strasse" and "Müller" matches "Mueller",
+/// the form without the umlauts ("muller") is added to the [`Token::alternatives`].
+/// Only the `Token`s detected as German are normalized,
+/// the normalizer is disabled by default and enabled using [`NormalizerOption::german_expansion`].
+pub struct GermanNormalizer;
+
+impl Normalizer for GermanNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        let mut expanded: Vec<(usize, String)> = Vec::new();
+        let mut stripped = String::new();
+        let mut last = None;
+        for c in token.lemma().chars() {
+            let (expansion, strip) = match c {
+                DIAERESIS if matches!(last, Some('a' | 'o' | 'u' | 'A' | 'O' | 'U')) => ("e", ""),
+                'ä' => ("ae", "a"),
+                'ö' => ("oe", "o"),
+                'ü' => ("ue", "u"),
+                'Ä' => ("Ae", "A"),
+                'Ö' => ("Oe", "O"),
+                'Ü' => ("Ue", "U"),
+                'ß' => ("ss", "ss"),
+                'ẞ' => ("SS", "SS"),
+                c => {
+                    expanded.push((c.len_utf8(), c.to_string()));
+                    if !(options.remove_diacritics && is_nonspacing_mark(c)) {
+                        stripped.push(c);
+                    }
+                    last = Some(c);
+                    continue;
+                }
+            };
+            expanded.push((c.len_utf8(), expansion.to_string()));
+            stripped.push_str(strip);
+            last = Some(c);
+        }
+
+        replace_chars(&mut token, expanded, options);
+        if stripped != token.lemma() {
+            token.alternatives.push(stripped);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Latin
+            && token.language == Some(Language::Deu)
+            && token
+                .lemma()
+                .chars()
+                .any(|c| matches!(c, DIAERESIS | 'ä' | 'ö' | 'ü' | 'Ä' | 'Ö' | 'Ü' | 'ß' | 'ẞ'))
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.german_expansion && self.should_normalize(token)
+    }
+
+    fn needs_language(&self, options: NormalizerOption) -> bool {
+        options.german_expansion
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::Language;
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Müller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Straße".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                ..Default::default()
+            },
+            // the text is decomposed.
+            Token {
+                lemma: Owned("Gro\u{308}ße".to_string()),
+                char_end: 6,
+                byte_end: 8,
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                ..Default::default()
+            },
+            // only German words are expanded.
+            Token {
+                lemma: Owned("Müller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                script: Script::Latin,
+                language: Some(Language::Tur),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Mueller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (2, 2), (1, 1), (1, 1), (1, 1), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                alternatives: vec!["Muller".to_string()],
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Strasse".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (2, 2), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Groesse".to_string()),
+                char_end: 6,
+                byte_end: 8,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (2, 1), (2, 2), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                alternatives: vec!["Grosse".to_string()],
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Müller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                script: Script::Latin,
+                language: Some(Language::Tur),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("mueller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (2, 2), (1, 1), (1, 1), (1, 1), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                alternatives: vec!["muller".to_string()],
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("strasse".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (2, 2), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("groesse".to_string()),
+                char_end: 6,
+                byte_end: 8,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (2, 1), (2, 2), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Deu),
+                alternatives: vec!["grosse".to_string()],
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("muller".to_string()),
+                char_end: 6,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (2, 1), (1, 1), (1, 1), (1, 1), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Tur),
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(
+        GermanNormalizer,
+        tokens(),
+        normalizer_result(),
+        normalized_tokens(),
+        NormalizerOption { german_expansion: true, ..Default::default() }
+    );
+
+    #[test]
+    fn disabled_by_default() {
+        let options = NormalizerOption::default();
+        assert!(!GermanNormalizer.should_normalize_with_options(&tokens()[0], options));
+        assert!(!GermanNormalizer.needs_language(options));
+    }
+
+    #[test]
+    fn either_spelling_matches() {
+        use crate::highlight::{Highlighter, MatchingWords};
+
+        let mut builder = crate::TokenizerBuilder::default();
+        builder.normalizer_option(NormalizerOption {
+            german_expansion: true,
+            create_char_map: true,
+            ..Default::default()
+        });
+        let tokenizer = builder.build();
+
+        let text = "Der Herr Müller wohnt seit vielen Jahren in dieser Straße";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let mut matching_words = MatchingWords::new();
+        for query in ["Mueller", "Muller", "Strasse"] {
+            let query = tokenizer.tokenize_query(query);
+            let term = &query.terms[0];
+            assert!(tokens.iter().any(|token| term.matches(token)), "{}", term.token.lemma());
+            matching_words.insert(term.token.lemma(), term.is_prefix);
+        }
+
+        let highlighter = Highlighter::new(text, &tokens);
+        assert_eq!(
+            highlighter.highlight(&matching_words, "[", "]"),
+            "Der Herr [Müller] wohnt seit vielen Jahren in dieser [Straße]"
+        );
+    }
+}
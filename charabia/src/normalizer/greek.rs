@@ -22,6 +22,10 @@ impl Normalizer for GreekNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.script == Script::Greek
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.greek_final_sigma && self.should_normalize(token)
+    }
 }
 
 #[cfg(test)]
@@ -42,6 +42,10 @@ impl Normalizer for JapaneseNormalizer {
             && matches!(token.language, None | Some(Language::Jpn))
             && !is_hiragana(token.lemma())
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.hiragana && self.should_normalize(token)
+    }
 }
 
 #[cfg(test)]
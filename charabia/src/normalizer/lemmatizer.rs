@@ -0,0 +1,407 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use fst::{IntoStreamer, Set, Streamer};
+use once_cell::sync::Lazy;
+use unicode_normalization::UnicodeNormalization;
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Token};
+
+static POL_LEMMAS: Lazy<LemmaDictionary> = Lazy::new(|| {
+    LemmaDictionary::new(&include_bytes!("../../dictionaries/fst/lemmatizer/pol.fst")[..]).unwrap()
+});
+static CES_LEMMAS: Lazy<LemmaDictionary> = Lazy::new(|| {
+    LemmaDictionary::new(&include_bytes!("../../dictionaries/fst/lemmatizer/ces.fst")[..]).unwrap()
+});
+static RUS_LEMMAS: Lazy<LemmaDictionary> = Lazy::new(|| {
+    LemmaDictionary::new(&include_bytes!("../../dictionaries/fst/lemmatizer/rus.fst")[..]).unwrap()
+});
+
+/// Dictionary mapping the inflected forms of a language to their lemma.
+///
+/// The dictionary is an FST set containing a `form\0lemma` key for each form,
+/// it can be built from the pairs of a text file containing a tab-separated `form\tlemma` pair by line,
+/// see the files in `dictionaries/txt/lemmatizer`, and saved using [`LemmaDictionary::as_bytes`].
+/// Forms and lemmas are lowercased and composed (NFC).
+#[derive(Clone)]
+pub struct LemmaDictionary {
+    set: Set<Cow<'static, [u8]>>,
+}
+
+impl LemmaDictionary {
+    /// Load a dictionary from the bytes of its FST.
+    pub fn new(bytes: impl Into<Cow<'static, [u8]>>) -> Result<Self, fst::Error> {
+        Ok(Self { set: Set::new(bytes.into())? })
+    }
+
+    /// Build a dictionary from `(form, lemma)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::normalizer::LemmaDictionary;
+    ///
+    /// let pairs = "myszy\tmysz\nmyszami\tmysz";
+    /// let dictionary =
+    ///     LemmaDictionary::from_pairs(pairs.lines().filter_map(|line| line.split_once('\t')))
+    ///         .unwrap();
+    ///
+    /// assert_eq!(dictionary.lemma("myszami"), Some("mysz".to_string()));
+    /// assert_eq!(dictionary.lemma("mysz"), None);
+    /// ```
+    pub fn from_pairs<I, F, L>(pairs: I) -> Result<Self, fst::Error>
+    where
+        I: IntoIterator<Item = (F, L)>,
+        F: AsRef<str>,
+        L: AsRef<str>,
+    {
+        let mut keys: Vec<_> = pairs
+            .into_iter()
+            .map(|(form, lemma)| [form.as_ref(), "\0", lemma.as_ref()].concat().into_bytes())
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+
+        let set = Set::from_iter(keys)?;
+        Ok(Self { set: Set::new(Cow::Owned(set.into_fst().into_inner()))? })
+    }
+
+    /// Returns the lemma of the provided form,
+    /// the first lemma in lexicographic order is returned if the form is ambiguous.
+    pub fn lemma(&self, form: &str) -> Option<String> {
+        let start = [form.as_bytes(), b"\0"].concat();
+        let end = [form.as_bytes(), b"\x01"].concat();
+        let mut stream = self.set.range().ge(&start).lt(&end).into_stream();
+        let key = stream.next()?;
+        String::from_utf8(key[start.len()..].to_vec()).ok()
+    }
+
+    /// Returns the bytes of the FST that can be loaded using [`LemmaDictionary::new`].
+    pub fn as_bytes(&self) -> &[u8] {
+        self.set.as_fst().as_bytes()
+    }
+}
+
+/// Replace inflected words by their lemma using a [`LemmaDictionary`] chosen by the [`Language`] of the `Token`.
+///
+/// The default normalizer contains small dictionaries of the most frequent words for Polish, Czech and Russian,
+/// dictionaries of other languages, or more complete ones, can be added with [`LemmatizerNormalizer::insert`]
+/// and the lemmatizer of the default pipeline replaced using [`NormalizerPipeline::replace`](super::NormalizerPipeline::replace).
+/// The normalizer is the first of the default pipeline, to look up the original words,
+/// the lemmas are then normalized by the following normalizers.
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalizer::{LemmaDictionary, LemmatizerNormalizer, NormalizerPipeline};
+/// use charabia::{Language, TokenizerBuilder};
+///
+/// let dictionary = LemmaDictionary::from_pairs([("mice", "mouse"), ("geese", "goose")]).unwrap();
+/// let mut lemmatizer = LemmatizerNormalizer::default();
+/// lemmatizer.insert(Language::Eng, dictionary);
+///
+/// let mut pipeline = NormalizerPipeline::default();
+/// pipeline.replace(lemmatizer);
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.normalizer_pipeline(pipeline);
+/// let tokenizer = builder.build();
+///
+/// let lemmas: Vec<_> = tokenizer
+///     .tokenize("The mice are running away from the geese")
+///     .filter(|token| token.is_word())
+///     .map(|token| token.lemma().to_string())
+///     .collect();
+/// assert_eq!(lemmas[1], "mouse");
+/// assert_eq!(lemmas[7], "goose");
+/// ```
+#[derive(Clone)]
+pub struct LemmatizerNormalizer {
+    dictionaries: HashMap<Language, LemmaDictionary>,
+}
+
+impl LemmatizerNormalizer {
+    /// Create a lemmatizer without any dictionary.
+    pub fn new() -> Self {
+        Self { dictionaries: HashMap::new() }
+    }
+
+    /// Add the dictionary of a language, replacing the previous one.
+    pub fn insert(&mut self, language: Language, dictionary: LemmaDictionary) -> &mut Self {
+        self.dictionaries.insert(language, dictionary);
+        self
+    }
+
+    /// Remove the dictionary of a language.
+    pub fn remove(&mut self, language: Language) -> &mut Self {
+        self.dictionaries.remove(&language);
+        self
+    }
+}
+
+impl Default for LemmatizerNormalizer {
+    fn default() -> Self {
+        let mut lemmatizer = Self::new();
+        lemmatizer
+            .insert(Language::Pol, POL_LEMMAS.clone())
+            .insert(Language::Ces, CES_LEMMAS.clone())
+            .insert(Language::Rus, RUS_LEMMAS.clone());
+
+        lemmatizer
+    }
+}
+
+impl Normalizer for LemmatizerNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        let Some(dictionary) = token.language.and_then(|language| self.dictionaries.get(&language))
+        else {
+            return token;
+        };
+
+        let form = token.lemma().nfc().collect::<String>().to_lowercase();
+        if let Some(lemma) = dictionary.lemma(&form) {
+            replace_lemma(&mut token, lemma, options);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.is_word()
+            && token.language.is_some_and(|language| self.dictionaries.contains_key(&language))
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.lemmatization && self.should_normalize(token)
+    }
+
+    fn needs_language(&self, options: NormalizerOption) -> bool {
+        options.lemmatization
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption, NormalizerPipeline};
+    use crate::{Language, TokenKind};
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Książkami".to_string()),
+                char_end: 9,
+                byte_end: 11,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Pol),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("шёл".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            // the form is not in the dictionary.
+            Token {
+                lemma: Owned("kočka".to_string()),
+                char_end: 5,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+            // no dictionary for the language.
+            Token {
+                lemma: Owned("psa".to_string()),
+                char_end: 3,
+                byte_end: 3,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Slk),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("książka".to_string()),
+                char_end: 9,
+                byte_end: 11,
+                kind: TokenKind::Word,
+                char_map: Some(vec![
+                    (1, 1),
+                    (1, 1),
+                    (1, 1),
+                    (2, 2),
+                    (2, 2),
+                    (1, 1),
+                    (1, 1),
+                    (1, 0),
+                    (1, 0),
+                ]),
+                script: Script::Latin,
+                language: Some(Language::Pol),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("идти".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 4)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("kočka".to_string()),
+                char_end: 5,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("psa".to_string()),
+                char_end: 3,
+                byte_end: 3,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Slk),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("ksiazka".to_string()),
+                char_end: 9,
+                byte_end: 11,
+                kind: TokenKind::Word,
+                char_map: Some(vec![
+                    (1, 1),
+                    (1, 1),
+                    (1, 1),
+                    (2, 1),
+                    (2, 1),
+                    (1, 1),
+                    (1, 1),
+                    (1, 0),
+                    (1, 0),
+                ]),
+                script: Script::Latin,
+                language: Some(Language::Pol),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("идти".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 4)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("kocka".to_string()),
+                char_end: 5,
+                byte_end: 6,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(1, 1), (1, 1), (2, 1), (1, 1), (1, 1)]),
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("psa".to_string()),
+                char_end: 3,
+                byte_end: 3,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Slk),
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(
+        LemmatizerNormalizer::default(),
+        tokens(),
+        normalizer_result(),
+        normalized_tokens()
+    );
+
+    #[test]
+    fn shipped_dictionaries() {
+        for (language, txt) in [
+            (Language::Pol, include_str!("../../dictionaries/txt/lemmatizer/pol.txt")),
+            (Language::Ces, include_str!("../../dictionaries/txt/lemmatizer/ces.txt")),
+            (Language::Rus, include_str!("../../dictionaries/txt/lemmatizer/rus.txt")),
+        ] {
+            let pairs = txt.lines().filter_map(|line| line.split_once('\t'));
+            let dictionary = LemmaDictionary::from_pairs(pairs).unwrap();
+            let shipped = &LemmatizerNormalizer::default().dictionaries[&language];
+            assert_eq!(dictionary.as_bytes(), shipped.as_bytes(), "{language:?}");
+        }
+    }
+
+    #[test]
+    fn runtime_dictionary() {
+        let mut lemmatizer = LemmatizerNormalizer::new();
+        let token = || Token {
+            lemma: Owned("psa".to_string()),
+            kind: TokenKind::Word,
+            language: Some(Language::Slk),
+            ..Default::default()
+        };
+        assert!(!lemmatizer.should_normalize_with_options(&token(), NormalizerOption::default()));
+
+        lemmatizer.insert(Language::Slk, LemmaDictionary::from_pairs([("psa", "pes")]).unwrap());
+        let options = NormalizerOption::default();
+        assert!(lemmatizer.should_normalize_with_options(&token(), options));
+        assert_eq!(lemmatizer.normalize(token(), options).lemma(), "pes");
+
+        let options = NormalizerOption { lemmatization: false, ..Default::default() };
+        assert!(!lemmatizer.should_normalize_with_options(&token(), options));
+    }
+
+    #[test]
+    fn extend_default_lemmatizer() {
+        let mut lemmatizer = LemmatizerNormalizer::default();
+        lemmatizer.insert(Language::Eng, LemmaDictionary::from_pairs([("mice", "mouse")]).unwrap());
+        let mut pipeline = NormalizerPipeline::default();
+        pipeline.replace(lemmatizer);
+        assert_eq!(pipeline.position::<LemmatizerNormalizer>(), Some(0));
+        assert_eq!(pipeline.len(), NormalizerPipeline::default().len());
+
+        let token = |lemma: &str, language| Token {
+            lemma: Owned(lemma.to_string()),
+            kind: TokenKind::Word,
+            script: Script::Latin,
+            language: Some(language),
+            ..Default::default()
+        };
+        let options = NormalizerOption::default();
+        assert_eq!(pipeline.normalize(token("Mice", Language::Eng), options).lemma(), "mouse");
+        // the shipped dictionaries are kept.
+        assert_eq!(pipeline.normalize(token("mogą", Language::Pol), options).lemma(), "moc");
+    }
+}
@@ -22,6 +22,10 @@ impl Normalizer for LowercaseNormalizer {
         matches!(token.script, Script::Latin | Script::Cyrillic | Script::Greek | Script::Georgian)
             && token.lemma.chars().any(char::is_uppercase)
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.lowercase && self.should_normalize(token)
+    }
 }
 
 #[cfg(test)]
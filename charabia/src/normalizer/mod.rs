@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::borrow::Cow;
 
 use once_cell::sync::Lazy;
@@ -7,12 +8,22 @@ pub use self::arabic::ArabicNormalizer;
 pub use self::chinese::ChineseNormalizer;
 pub use self::compatibility_decomposition::CompatibilityDecompositionNormalizer;
 pub use self::control_char::ControlCharNormalizer;
+#[cfg(feature = "cyrillic")]
+pub use self::cyrillic::CyrillicNormalizer;
+#[cfg(feature = "german")]
+pub use self::german::GermanNormalizer;
+#[cfg(feature = "greek")]
+pub use self::greek::GreekNormalizer;
 #[cfg(feature = "japanese-transliteration")]
 pub use self::japanese::JapaneseNormalizer;
+#[cfg(feature = "lemmatization")]
+pub use self::lemmatizer::{LemmaDictionary, LemmatizerNormalizer};
 pub use self::lowercase::LowercaseNormalizer;
+pub use self::nonspacing_mark::NonspacingMarkNormalizer;
+#[cfg(feature = "stemming")]
+pub use self::stemming::StemmingNormalizer;
 use crate::classifier::ClassifiedTokenIter;
-use crate::normalizer::greek::GreekNormalizer;
-use crate::normalizer::nonspacing_mark::NonspacingMarkNormalizer;
+use crate::shared::Shared;
 use crate::Token;
 
 mod arabic;
@@ -20,33 +31,157 @@ mod arabic;
 mod chinese;
 mod compatibility_decomposition;
 mod control_char;
+#[cfg(feature = "cyrillic")]
+mod cyrillic;
+#[cfg(feature = "german")]
+mod german;
 #[cfg(feature = "greek")]
 mod greek;
 #[cfg(feature = "japanese-transliteration")]
 mod japanese;
+#[cfg(feature = "lemmatization")]
+mod lemmatizer;
 mod lowercase;
 mod nonspacing_mark;
+#[cfg(feature = "stemming")]
+mod stemming;
+
+/// Default [`NormalizerPipeline`] used by [`Token::normalize`].
+pub static NORMALIZERS: Lazy<NormalizerPipeline> = Lazy::new(NormalizerPipeline::default);
+
+/// Ordered list of [`Normalizer`]s applied on each [`Token`].
+///
+/// The default pipeline contains all the normalizers of the library,
+/// normalizers can be added, removed, or reordered to fit specific needs.
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalizer::{LowercaseNormalizer, NonspacingMarkNormalizer, NormalizerPipeline};
+/// use charabia::TokenizerBuilder;
+///
+/// let mut pipeline = NormalizerPipeline::default();
+/// pipeline.remove::<LowercaseNormalizer>().remove::<NonspacingMarkNormalizer>();
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.normalizer_pipeline(pipeline);
+/// let tokenizer = builder.build();
+///
+/// let mut tokens = tokenizer.tokenize("SKU-Été");
+/// assert_eq!(tokens.next().unwrap().lemma(), "SKU");
+/// assert_eq!(tokens.next().unwrap().lemma(), "-");
+/// assert_eq!(tokens.next().unwrap().lemma(), "E\u{301}te\u{301}");
+/// ```
+pub struct NormalizerPipeline {
+    normalizers: Vec<(TypeId, Box<dyn Normalizer>)>,
+}
+
+impl NormalizerPipeline {
+    /// Create an empty pipeline, no normalization will be applied on `Token`s.
+    pub fn new() -> Self {
+        Self { normalizers: Vec::new() }
+    }
+
+    /// Append a normalizer at the end of the pipeline.
+    pub fn push<N: Normalizer + 'static>(&mut self, normalizer: N) -> &mut Self {
+        self.normalizers.push((TypeId::of::<N>(), Box::new(normalizer)));
+        self
+    }
+
+    /// Insert a normalizer at position `index` in the pipeline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert<N: Normalizer + 'static>(&mut self, index: usize, normalizer: N) -> &mut Self {
+        self.normalizers.insert(index, (TypeId::of::<N>(), Box::new(normalizer)));
+        self
+    }
+
+    /// Remove every normalizer of type `N` from the pipeline.
+    pub fn remove<N: Normalizer + 'static>(&mut self) -> &mut Self {
+        self.normalizers.retain(|(type_id, _)| *type_id != TypeId::of::<N>());
+        self
+    }
+
+    /// Replace the first normalizer of type `N` of the pipeline by the provided one, keeping its position,
+    /// the normalizer is appended if the pipeline doesn't contain any normalizer of type `N`.
+    pub fn replace<N: Normalizer + 'static>(&mut self, normalizer: N) -> &mut Self {
+        match self.position::<N>() {
+            Some(index) => self.normalizers[index].1 = Box::new(normalizer),
+            None => {
+                self.push(normalizer);
+            }
+        }
+        self
+    }
+
+    /// Returns the position of the first normalizer of type `N` in the pipeline.
+    pub fn position<N: Normalizer + 'static>(&self) -> Option<usize> {
+        self.normalizers.iter().position(|(type_id, _)| *type_id == TypeId::of::<N>())
+    }
+
+    /// Returns the number of normalizers in the pipeline.
+    pub fn len(&self) -> usize {
+        self.normalizers.len()
+    }
+
+    /// Returns true if the pipeline doesn't contain any normalizer.
+    pub fn is_empty(&self) -> bool {
+        self.normalizers.is_empty()
+    }
+
+    /// Returns an iterator over the normalizers of the pipeline in application order.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Normalizer> {
+        self.normalizers.iter().map(|(_, normalizer)| normalizer.as_ref())
+    }
+
+    /// Returns true if a normalizer of the pipeline enabled by the `options` needs the [`Language`](crate::Language)
+    /// of the `Token`s, see [`Normalizer::needs_language`].
+    pub fn needs_language(&self, options: NormalizerOption) -> bool {
+        self.iter().any(|normalizer| normalizer.needs_language(options))
+    }
+
+    /// Normalize [`Token`] using all the compatible Normalizers of the pipeline.
+    pub fn normalize<'o>(&self, mut token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        for normalizer in self.iter() {
+            if normalizer.should_normalize_with_options(&token, options) {
+                token = normalizer.normalize(token, options);
+            }
+        }
+
+        token
+    }
+}
 
-/// List of [`Normalizer`]s used by [`Normalize::normalize`].
-pub static NORMALIZERS: Lazy<Vec<Box<dyn Normalizer>>> = Lazy::new(|| {
-    vec![
-        Box::new(CompatibilityDecompositionNormalizer),
-        Box::new(LowercaseNormalizer),
+impl Default for NormalizerPipeline {
+    fn default() -> Self {
+        let mut pipeline = Self::new();
+        #[cfg(feature = "lemmatization")]
+        pipeline.push(LemmatizerNormalizer::default());
+        pipeline.push(CompatibilityDecompositionNormalizer).push(LowercaseNormalizer);
         #[cfg(feature = "chinese")]
-        Box::new(ChineseNormalizer),
+        pipeline.push(ChineseNormalizer);
         #[cfg(feature = "japanese-transliteration")]
-        Box::new(JapaneseNormalizer),
+        pipeline.push(JapaneseNormalizer);
         #[cfg(feature = "greek")]
-        Box::new(GreekNormalizer),
-        Box::new(ControlCharNormalizer),
-        Box::new(NonspacingMarkNormalizer),
-        Box::new(ArabicNormalizer),
-    ]
-});
+        pipeline.push(GreekNormalizer);
+        #[cfg(feature = "cyrillic")]
+        pipeline.push(CyrillicNormalizer);
+        #[cfg(feature = "german")]
+        pipeline.push(GermanNormalizer);
+        pipeline.push(ControlCharNormalizer).push(NonspacingMarkNormalizer).push(ArabicNormalizer);
+        #[cfg(feature = "stemming")]
+        pipeline.push(StemmingNormalizer);
+
+        pipeline
+    }
+}
 
 /// Iterator over Normalized [`Token`]s.
 pub struct NormalizedTokenIter<'o, 'al, 'sw, A> {
     token_iter: ClassifiedTokenIter<'o, 'al, 'sw, A>,
+    pipeline: Shared<'al, NormalizerPipeline>,
     options: NormalizerOption,
 }
 
@@ -54,14 +189,202 @@ impl<'o, A: AsRef<[u8]>> Iterator for NormalizedTokenIter<'o, '_, '_, A> {
     type Item = Token<'o>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.token_iter.next()?.normalize(self.options))
+        Some(self.pipeline.normalize(self.token_iter.next()?, self.options))
     }
 }
 
 /// Structure for providing options to a normalizer.
-#[derive(Clone, Copy, Default)]
+///
+/// Except `create_char_map`, `german_expansion`, `stemming` and `keep_unstemmed`, every option is enabled by default
+/// and switches off the corresponding built-in normalization when disabled,
+/// the German expansion and the stemming change the words too much to be applied without being asked for.
+/// The options of the normalizers behind a feature only exist when the feature is enabled.
+///
+/// Build it from [`NormalizerOption::default`], with the struct update syntax or the setters,
+/// so the struct literals keep compiling whatever the enabled features.
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalizer::NormalizerOption;
+/// use charabia::TokenizerBuilder;
+///
+/// let mut options = NormalizerOption::default();
+/// options.remove_diacritics(false);
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.normalizer_option(options);
+/// let tokenizer = builder.build();
+///
+/// let mut tokens = tokenizer.tokenize("Été");
+/// assert_eq!(tokens.next().unwrap().lemma(), "e\u{301}te\u{301}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NormalizerOption {
+    /// Create the `char_map` of the normalized [`Token`]s.
     pub create_char_map: bool,
+    /// Lowercase characters, see [`LowercaseNormalizer`].
+    pub lowercase: bool,
+    /// Decompose characters using the Unicode Normalization Form KD,
+    /// see [`CompatibilityDecompositionNormalizer`].
+    pub compatibility_decomposition: bool,
+    /// Remove diacritics and other nonspacing marks, see [`NonspacingMarkNormalizer`].
+    pub remove_diacritics: bool,
+    /// Convert Chinese characters into Pinyin, see [`ChineseNormalizer`].
+    #[cfg(feature = "chinese")]
+    pub pinyin: bool,
+    /// Fold Chinese characters variants (kVariants), see [`ChineseNormalizer`].
+    #[cfg(feature = "chinese")]
+    pub kvariants: bool,
+    /// Convert Katakana and Kanji into Hiragana, see [`JapaneseNormalizer`].
+    #[cfg(feature = "japanese-transliteration")]
+    pub hiragana: bool,
+    /// Convert the Greek final sigma into an ordinary sigma, see [`GreekNormalizer`].
+    #[cfg(feature = "greek")]
+    pub greek_final_sigma: bool,
+    /// Remove the Arabic Tatweel characters, see [`ArabicNormalizer`].
+    pub remove_tatweel: bool,
+    /// Fold the Cyrillic letters commonly mixed up, like `ё` and `е`, see [`CyrillicNormalizer`].
+    #[cfg(feature = "cyrillic")]
+    pub fold_cyrillic: bool,
+    /// Expand the German `ß` and umlauts, like `ü` into `ue`, see [`GermanNormalizer`], disabled by default.
+    #[cfg(feature = "german")]
+    pub german_expansion: bool,
+    /// Replace the inflected words by their lemma, see [`LemmatizerNormalizer`].
+    #[cfg(feature = "lemmatization")]
+    pub lemmatization: bool,
+    /// Reduce the words to their stem, see [`StemmingNormalizer`], disabled by default.
+    #[cfg(feature = "stemming")]
+    pub stemming: bool,
+    /// Keep the unstemmed lemma in the [`Token::alternatives`] of the stemmed words, see [`StemmingNormalizer`].
+    #[cfg(feature = "stemming")]
+    pub keep_unstemmed: bool,
+}
+
+impl Default for NormalizerOption {
+    fn default() -> Self {
+        Self {
+            create_char_map: false,
+            lowercase: true,
+            compatibility_decomposition: true,
+            remove_diacritics: true,
+            #[cfg(feature = "chinese")]
+            pinyin: true,
+            #[cfg(feature = "chinese")]
+            kvariants: true,
+            #[cfg(feature = "japanese-transliteration")]
+            hiragana: true,
+            #[cfg(feature = "greek")]
+            greek_final_sigma: true,
+            remove_tatweel: true,
+            #[cfg(feature = "cyrillic")]
+            fold_cyrillic: true,
+            #[cfg(feature = "german")]
+            german_expansion: false,
+            #[cfg(feature = "lemmatization")]
+            lemmatization: true,
+            #[cfg(feature = "stemming")]
+            stemming: false,
+            #[cfg(feature = "stemming")]
+            keep_unstemmed: false,
+        }
+    }
+}
+
+impl NormalizerOption {
+    /// Create the `char_map` of the normalized [`Token`]s.
+    pub fn create_char_map(&mut self, create_char_map: bool) -> &mut Self {
+        self.create_char_map = create_char_map;
+        self
+    }
+
+    /// Lowercase characters, see [`LowercaseNormalizer`].
+    pub fn lowercase(&mut self, lowercase: bool) -> &mut Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Decompose characters using the Unicode Normalization Form KD,
+    /// see [`CompatibilityDecompositionNormalizer`].
+    pub fn compatibility_decomposition(&mut self, compatibility_decomposition: bool) -> &mut Self {
+        self.compatibility_decomposition = compatibility_decomposition;
+        self
+    }
+
+    /// Remove diacritics and other nonspacing marks, see [`NonspacingMarkNormalizer`].
+    pub fn remove_diacritics(&mut self, remove_diacritics: bool) -> &mut Self {
+        self.remove_diacritics = remove_diacritics;
+        self
+    }
+
+    /// Convert Chinese characters into Pinyin, see [`ChineseNormalizer`].
+    #[cfg(feature = "chinese")]
+    pub fn pinyin(&mut self, pinyin: bool) -> &mut Self {
+        self.pinyin = pinyin;
+        self
+    }
+
+    /// Fold Chinese characters variants (kVariants), see [`ChineseNormalizer`].
+    #[cfg(feature = "chinese")]
+    pub fn kvariants(&mut self, kvariants: bool) -> &mut Self {
+        self.kvariants = kvariants;
+        self
+    }
+
+    /// Convert Katakana and Kanji into Hiragana, see [`JapaneseNormalizer`].
+    #[cfg(feature = "japanese-transliteration")]
+    pub fn hiragana(&mut self, hiragana: bool) -> &mut Self {
+        self.hiragana = hiragana;
+        self
+    }
+
+    /// Convert the Greek final sigma into an ordinary sigma, see [`GreekNormalizer`].
+    #[cfg(feature = "greek")]
+    pub fn greek_final_sigma(&mut self, greek_final_sigma: bool) -> &mut Self {
+        self.greek_final_sigma = greek_final_sigma;
+        self
+    }
+
+    /// Remove the Arabic Tatweel characters, see [`ArabicNormalizer`].
+    pub fn remove_tatweel(&mut self, remove_tatweel: bool) -> &mut Self {
+        self.remove_tatweel = remove_tatweel;
+        self
+    }
+
+    /// Fold the Cyrillic letters commonly mixed up, like `ё` and `е`, see [`CyrillicNormalizer`].
+    #[cfg(feature = "cyrillic")]
+    pub fn fold_cyrillic(&mut self, fold_cyrillic: bool) -> &mut Self {
+        self.fold_cyrillic = fold_cyrillic;
+        self
+    }
+
+    /// Expand the German `ß` and umlauts, like `ü` into `ue`, see [`GermanNormalizer`], disabled by default.
+    #[cfg(feature = "german")]
+    pub fn german_expansion(&mut self, german_expansion: bool) -> &mut Self {
+        self.german_expansion = german_expansion;
+        self
+    }
+
+    /// Replace the inflected words by their lemma, see [`LemmatizerNormalizer`].
+    #[cfg(feature = "lemmatization")]
+    pub fn lemmatization(&mut self, lemmatization: bool) -> &mut Self {
+        self.lemmatization = lemmatization;
+        self
+    }
+
+    /// Reduce the words to their stem, see [`StemmingNormalizer`], disabled by default.
+    #[cfg(feature = "stemming")]
+    pub fn stemming(&mut self, stemming: bool) -> &mut Self {
+        self.stemming = stemming;
+        self
+    }
+
+    /// Keep the unstemmed lemma in the [`Token::alternatives`] of the stemmed words, see [`StemmingNormalizer`].
+    #[cfg(feature = "stemming")]
+    pub fn keep_unstemmed(&mut self, keep_unstemmed: bool) -> &mut Self {
+        self.keep_unstemmed = keep_unstemmed;
+        self
+    }
 }
 
 /// Trait defining a normalizer.
@@ -75,6 +398,91 @@ pub trait Normalizer: Sync + Send {
     ///
     /// Some normalizer are specialized for a `Script` or/and a `Language` and shouldn't be called on every `Token`s.
     fn should_normalize(&self, token: &Token) -> bool;
+
+    /// Same as [`should_normalize`](Self::should_normalize) but the normalizer can also be disabled
+    /// using the provided [`NormalizerOption`].
+    ///
+    /// By default, the options are ignored.
+    fn should_normalize_with_options(&self, token: &Token, _options: NormalizerOption) -> bool {
+        self.should_normalize(token)
+    }
+
+    /// Return true if the normalizer relies on the [`Language`](crate::Language) of the `Token`s
+    /// when it's enabled by the provided [`NormalizerOption`].
+    ///
+    /// The `Language` of a `Token` is only detected when it's needed,
+    /// a tokenizer whose pipeline contains such an enabled normalizer always detects it.
+    fn needs_language(&self, _options: NormalizerOption) -> bool {
+        false
+    }
+}
+
+/// Replace the lemma of the [`Token`] by the concatenation of the normalized strings of its chars,
+/// each normalized string is given with the byte length of its original char in the lemma.
+///
+/// The normalized lengths of the `char_map` are recomputed,
+/// the `char_map` is created from the chars of the lemma if it doesn't exist.
+#[cfg(any(feature = "cyrillic", feature = "german"))]
+fn replace_chars(token: &mut Token, normalized: Vec<(usize, String)>, options: NormalizerOption) {
+    if options.create_char_map {
+        match token.char_map.as_mut() {
+            Some(char_map) => {
+                let mut normalized = normalized.iter();
+                for (_, normalized_len) in char_map.iter_mut() {
+                    let mut consumed = 0;
+                    let mut len = 0;
+                    while consumed < *normalized_len as usize {
+                        let (char_len, s) = normalized.next().unwrap();
+                        consumed += char_len;
+                        len += s.len();
+                    }
+                    *normalized_len = len as u8;
+                }
+            }
+            None => {
+                let char_map =
+                    normalized.iter().map(|(char_len, s)| (*char_len as u8, s.len() as u8));
+                token.char_map = Some(char_map.collect());
+            }
+        }
+    }
+
+    token.lemma = Cow::Owned(normalized.into_iter().map(|(_, s)| s).collect());
+}
+
+/// Replace the lemma of the [`Token`] by a new one, like a stem or a dictionary lemma,
+/// and returns the replaced lemma.
+///
+/// The bytes of the new lemma are attributed in order to the chars of the `char_map`,
+/// each char keeps at most its previous normalized length, rounded up to a char boundary of the new lemma,
+/// and the remaining bytes are attributed to the last char.
+#[cfg(any(feature = "stemming", feature = "lemmatization"))]
+fn replace_lemma<'o>(
+    token: &mut Token<'o>,
+    lemma: String,
+    options: NormalizerOption,
+) -> Cow<'o, str> {
+    if options.create_char_map {
+        let char_map = token.char_map.get_or_insert_with(|| {
+            token.lemma.chars().map(|c| (c.len_utf8() as u8, c.len_utf8() as u8)).collect()
+        });
+
+        let mut start = 0;
+        for (_, normalized_len) in char_map.iter_mut() {
+            let mut end = (start + *normalized_len as usize).min(lemma.len());
+            while !lemma.is_char_boundary(end) {
+                end += 1;
+            }
+            *normalized_len = (end - start) as u8;
+            start = end;
+        }
+
+        if let Some((_, normalized_len)) = char_map.last_mut() {
+            *normalized_len += (lemma.len() - start) as u8;
+        }
+    }
+
+    std::mem::replace(&mut token.lemma, Cow::Owned(lemma))
 }
 
 // Allow taking &Cow as argument to spare the allocation if it is already borrowed (and thus ~Copy)
@@ -127,6 +535,14 @@ pub trait CharNormalizer: Sync + Send {
     ///
     /// Some normalizer are specialized for a `Script` or/and a `Language` and shouldn't be called on every `Token`s.
     fn should_normalize(&self, token: &Token) -> bool;
+
+    /// Same as [`should_normalize`](Self::should_normalize) but the normalizer can also be disabled
+    /// using the provided [`NormalizerOption`].
+    ///
+    /// By default, the options are ignored.
+    fn should_normalize_with_options(&self, token: &Token, _options: NormalizerOption) -> bool {
+        CharNormalizer::should_normalize(self, token)
+    }
 }
 
 impl<T> Normalizer for T
@@ -175,6 +591,10 @@ where
     fn should_normalize(&self, token: &Token) -> bool {
         CharNormalizer::should_normalize(self, token)
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        CharNormalizer::should_normalize_with_options(self, token, options)
+    }
 }
 
 pub enum CharOrStr {
@@ -199,7 +619,24 @@ impl<'o, 'al, 'sw, A> ClassifiedTokenIter<'o, 'al, 'sw, A> {
     ///
     /// A Latin `Token` would not be normalized the same as a Chinese `Token`.
     pub fn normalize(self, options: NormalizerOption) -> NormalizedTokenIter<'o, 'al, 'sw, A> {
-        NormalizedTokenIter { token_iter: self, options }
+        self.normalize_with_pipeline(&NORMALIZERS, options)
+    }
+
+    /// Normalize [`Token`]s using all the compatible Normalizers of the provided [`NormalizerPipeline`].
+    pub fn normalize_with_pipeline(
+        self,
+        pipeline: &'al NormalizerPipeline,
+        options: NormalizerOption,
+    ) -> NormalizedTokenIter<'o, 'al, 'sw, A> {
+        self.normalize_with_shared_pipeline(Shared::Borrowed(pipeline), options)
+    }
+
+    pub(crate) fn normalize_with_shared_pipeline(
+        self,
+        pipeline: Shared<'al, NormalizerPipeline>,
+        options: NormalizerOption,
+    ) -> NormalizedTokenIter<'o, 'al, 'sw, A> {
+        NormalizedTokenIter { token_iter: self, pipeline, options }
     }
 }
 
@@ -207,14 +644,8 @@ impl Token<'_> {
     /// Normalize [`Token`] using all the compatible Normalizers.
     ///
     /// A Latin `Token` would not be normalized the same as a Chinese `Token`.
-    pub fn normalize(mut self, options: NormalizerOption) -> Self {
-        for normalizer in NORMALIZERS.iter() {
-            if normalizer.should_normalize(&self) {
-                self = normalizer.normalize(self, options);
-            }
-        }
-
-        self
+    pub fn normalize(self, options: NormalizerOption) -> Self {
+        NORMALIZERS.normalize(self, options)
     }
 }
 
@@ -222,15 +653,25 @@ impl Token<'_> {
 mod test {
     macro_rules! test_normalizer {
         ($normalizer:expr, $tokens:expr, $normalizer_result:expr, $global_result:expr) => {
+            test_normalizer!(
+                $normalizer,
+                $tokens,
+                $normalizer_result,
+                $global_result,
+                NormalizerOption::default()
+            );
+        };
+        ($normalizer:expr, $tokens:expr, $normalizer_result:expr, $global_result:expr, $options:expr) => {
             use super::*;
             use crate::{Script, Token};
 
             #[test]
             fn normalizer_normalize() {
+                let options = NormalizerOption { create_char_map: true, ..$options };
                 let normalized_tokens: Vec<_> = $tokens
                     .into_iter()
-                    .map(|token| if Normalizer::should_normalize(&$normalizer, &token) {
-                        $normalizer.normalize(token, NormalizerOption { create_char_map: true })
+                    .map(|token| if Normalizer::should_normalize_with_options(&$normalizer, &token, options) {
+                        $normalizer.normalize(token, options)
                     } else {
                         token
                     })
@@ -250,7 +691,7 @@ it's probably due to a bug in the normalizer or a mistake in the provided normal
 
             #[test]
             fn global_normalize() {
-                let options = NormalizerOption { create_char_map: true };
+                let options = NormalizerOption { create_char_map: true, ..$options };
                 let normalized_tokens: Vec<_> = $tokens.into_iter().map(|t| t.normalize(options)).collect();
                 assert_eq!(
                     &normalized_tokens[..],
@@ -258,13 +699,101 @@ it's probably due to a bug in the normalizer or a mistake in the provided normal
                     r#"
 Global normalization pipeline didn't normalize tokens as expected.
 
-help: The `global_result` provided to `test_normalizer!` does not corresponds to the output of the normalizer pipeline, it's probably because the normalizer is missing from `NORMALIZERS` list or because an other normalizer has alterated the token.
-Check if the `NORMALIZERS` list in `charabia/src/normalizer/mod.rs` contains the tested Normalizer.
+help: The `global_result` provided to `test_normalizer!` does not corresponds to the output of the normalizer pipeline, it's probably because the normalizer is missing from the default `NormalizerPipeline` or because an other normalizer has alterated the token.
+Check if the default `NormalizerPipeline` in `charabia/src/normalizer/mod.rs` contains the tested Normalizer.
 Make sure that normalized tokens are valid or change the trigger condition of the noisy normalizers by updating `should_normalize`.
 "#
                 );
             }
         };
     }
+    use std::borrow::Cow;
+
     pub(crate) use test_normalizer;
+
+    use super::*;
+    use crate::Script;
+
+    struct SkuNormalizer;
+
+    impl CharNormalizer for SkuNormalizer {
+        fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+            (c != '-').then(|| c.into())
+        }
+
+        fn should_normalize(&self, token: &Token) -> bool {
+            token.lemma().starts_with("SKU")
+        }
+    }
+
+    #[test]
+    fn pipeline_edition() {
+        let mut pipeline = NormalizerPipeline::default();
+        let len = pipeline.len();
+        // the lemmatizer comes first when it's enabled.
+        let first = pipeline.position::<CompatibilityDecompositionNormalizer>().unwrap();
+        assert_eq!(pipeline.position::<LowercaseNormalizer>(), Some(first + 1));
+
+        pipeline.remove::<LowercaseNormalizer>().remove::<NonspacingMarkNormalizer>();
+        assert_eq!(pipeline.len(), len - 2);
+        assert_eq!(pipeline.position::<LowercaseNormalizer>(), None);
+
+        pipeline.insert(0, SkuNormalizer);
+        assert_eq!(pipeline.position::<SkuNormalizer>(), Some(0));
+        pipeline.replace(SkuNormalizer);
+        assert_eq!(pipeline.position::<SkuNormalizer>(), Some(0));
+        assert_eq!(pipeline.len(), len - 1);
+        assert_eq!(pipeline.position::<CompatibilityDecompositionNormalizer>(), Some(first + 1));
+
+        let token = Token {
+            lemma: Cow::Borrowed("SKU-Été"),
+            char_end: 7,
+            byte_end: 9,
+            script: Script::Latin,
+            ..Default::default()
+        };
+        let token = pipeline.normalize(token, NormalizerOption::default());
+        assert_eq!(token.lemma(), "SKUE\u{301}te\u{301}");
+
+        let token =
+            Token { lemma: Cow::Borrowed("Été"), script: Script::Latin, ..Default::default() };
+        assert_eq!(
+            NormalizerPipeline::new().normalize(token.clone(), NormalizerOption::default()),
+            token
+        );
+        assert_eq!(NORMALIZERS.normalize(token, NormalizerOption::default()).lemma(), "ete");
+    }
+
+    #[test]
+    fn disabled_normalizations() {
+        let normalize = |lemma: &'static str, script: Script, options: NormalizerOption| {
+            let token = Token { lemma: Cow::Borrowed(lemma), script, ..Default::default() };
+            NORMALIZERS.normalize(token, options).lemma.into_owned()
+        };
+
+        let options = NormalizerOption::default();
+        assert_eq!(normalize("Élan", Script::Latin, options), "elan");
+        assert_eq!(normalize("ﬁ", Script::Latin, options), "fi");
+        #[cfg(feature = "greek")]
+        assert_eq!(normalize("Αγαπητός", Script::Greek, options), "αγαπητοσ");
+        assert_eq!(normalize("الحمــد", Script::Arabic, options), "الحمد");
+
+        let options = NormalizerOption { lowercase: false, ..Default::default() };
+        assert_eq!(normalize("Élan", Script::Latin, options), "Elan");
+
+        let options = NormalizerOption { remove_diacritics: false, ..Default::default() };
+        assert_eq!(normalize("Élan", Script::Latin, options), "e\u{301}lan");
+
+        let options = NormalizerOption { compatibility_decomposition: false, ..Default::default() };
+        assert_eq!(normalize("ﬁ", Script::Latin, options), "ﬁ");
+
+        #[cfg(feature = "greek")]
+        {
+            let options = NormalizerOption { greek_final_sigma: false, ..Default::default() };
+            assert_eq!(normalize("Αγαπητός", Script::Greek, options), "αγαπητος");
+        }
+
+        let options = NormalizerOption { remove_tatweel: false, ..Default::default() };
+        assert_eq!(normalize("الحمــد", Script::Arabic, options), "الحمــد");
+    }
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use super::CharNormalizer;
+use super::{CharNormalizer, NormalizerOption};
 use crate::detection::Script;
 use crate::normalizer::CharOrStr;
 use crate::Token;
@@ -31,10 +31,14 @@ impl CharNormalizer for NonspacingMarkNormalizer {
             Script::Hebrew | Script::Thai | Script::Arabic | Script::Latin | Script::Greek
         ) && token.lemma().chars().any(is_nonspacing_mark)
     }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.remove_diacritics && CharNormalizer::should_normalize(self, token)
+    }
 }
 
 /// Returns true if the character is a nonspacing mark
-fn is_nonspacing_mark(c: char) -> bool {
+pub(super) fn is_nonspacing_mark(c: char) -> bool {
     NONSPACING_MARKS.contains(&(c as u32))
 }
 
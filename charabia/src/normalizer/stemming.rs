@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use rust_stemmers::{Algorithm, Stemmer};
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Token};
+
+/// Reduce words to their stem using the [Snowball](https://snowballstem.org/) stemmer of their [`Language`].
+///
+/// Supported languages are English, French, German, Spanish, Portuguese, Italian, Dutch, Swedish, Danish,
+/// Norwegian, Finnish, Hungarian, Romanian, Russian and Turkish,
+/// words of another language, or whose language is unknown, are left untouched.
+///
+/// The normalizer is added at the end of the default pipeline
+/// to stem the lemmas once they are lowercased and their diacritics are removed,
+/// it is disabled by default and enabled using [`NormalizerOption::stemming`].
+/// The unstemmed lemma can be kept using [`NormalizerOption::keep_unstemmed`].
+pub struct StemmingNormalizer;
+
+impl Normalizer for StemmingNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: NormalizerOption) -> Token<'o> {
+        let Some(algorithm) = token.language.and_then(algorithm) else {
+            return token;
+        };
+
+        let stem = match Stemmer::create(algorithm).stem(token.lemma()) {
+            Cow::Borrowed(stem) if stem.len() == token.lemma.len() => return token,
+            stem => stem.into_owned(),
+        };
+
+        let unstemmed = replace_lemma(&mut token, stem, options);
+        if options.keep_unstemmed {
+            token.alternatives.push(unstemmed.into_owned());
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.is_word() && token.language.and_then(algorithm).is_some()
+    }
+
+    fn should_normalize_with_options(&self, token: &Token, options: NormalizerOption) -> bool {
+        options.stemming && self.should_normalize(token)
+    }
+
+    fn needs_language(&self, options: NormalizerOption) -> bool {
+        options.stemming
+    }
+}
+
+/// Returns the Snowball algorithm stemming the words of the provided language.
+fn algorithm(language: Language) -> Option<Algorithm> {
+    match language {
+        Language::Eng => Some(Algorithm::English),
+        Language::Fra => Some(Algorithm::French),
+        Language::Deu => Some(Algorithm::German),
+        Language::Spa => Some(Algorithm::Spanish),
+        Language::Por => Some(Algorithm::Portuguese),
+        Language::Ita => Some(Algorithm::Italian),
+        Language::Nld => Some(Algorithm::Dutch),
+        Language::Swe => Some(Algorithm::Swedish),
+        Language::Dan => Some(Algorithm::Danish),
+        Language::Nob => Some(Algorithm::Norwegian),
+        Language::Fin => Some(Algorithm::Finnish),
+        Language::Hun => Some(Algorithm::Hungarian),
+        Language::Ron => Some(Algorithm::Romanian),
+        Language::Rus => Some(Algorithm::Russian),
+        Language::Tur => Some(Algorithm::Turkish),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::{Language, TokenKind};
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("chevaux".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книги".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            // the language is unknown.
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                ..Default::default()
+            },
+            // the language is not supported.
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("cheval".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 1), (1, 1), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книг".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("cheval".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 1), (1, 1), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книг".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Ces),
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(
+        StemmingNormalizer,
+        tokens(),
+        normalizer_result(),
+        normalized_tokens(),
+        NormalizerOption { stemming: true, ..Default::default() }
+    );
+
+    #[test]
+    fn keep_unstemmed() {
+        let options =
+            NormalizerOption { stemming: true, keep_unstemmed: true, ..Default::default() };
+        let token = StemmingNormalizer.normalize(tokens().remove(0), options);
+        assert_eq!(token.lemma(), "run");
+        assert_eq!(token.alternatives, ["running"]);
+
+        // the stemming is disabled by default.
+        let options = NormalizerOption::default();
+        assert!(!StemmingNormalizer.should_normalize_with_options(&tokens()[0], options));
+    }
+
+    #[test]
+    fn unstemmed_matches() {
+        let mut builder = crate::TokenizerBuilder::default();
+        builder.normalizer_option(NormalizerOption {
+            stemming: true,
+            keep_unstemmed: true,
+            ..Default::default()
+        });
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> =
+            tokenizer.tokenize("The foxes were running over the sleeping dogs").collect();
+        let running = tokens.iter().find(|token| token.lemma() == "run").unwrap();
+        assert_eq!(running.lemmas().collect::<Vec<_>>(), ["run", "running"]);
+
+        // a prefix of the unstemmed word matches the stemmed word.
+        let query = tokenizer.tokenize_query("runni");
+        assert!(query.terms[0].is_prefix);
+        assert!(query.terms[0].matches(running));
+    }
+
+    #[test]
+    fn tokenizer_detects_language() {
+        let mut builder = crate::TokenizerBuilder::default();
+        builder.normalizer_option(NormalizerOption { stemming: true, ..Default::default() });
+        let tokenizer = builder.build();
+        let lemmas: Vec<_> = tokenizer
+            .tokenize("The foxes were jumping over the sleeping dogs")
+            .filter(|token| token.is_word())
+            .map(|token| token.lemma().to_string())
+            .collect();
+        assert_eq!(lemmas, ["the", "fox", "were", "jump", "over", "the", "sleep", "dog"]);
+
+        // the language is not detected for a disabled stemming.
+        assert!(StemmingNormalizer
+            .needs_language(NormalizerOption { stemming: true, ..Default::default() }));
+        assert!(!StemmingNormalizer.needs_language(NormalizerOption::default()));
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+
+use crate::normalizer::NormalizedTokenIter;
+use crate::synonym::SynonymTokenIter;
+use crate::{SeparatorKind, Token};
+
+/// Default position gap between two words separated by a hard separator.
+pub const DEFAULT_HARD_SEPARATOR_GAP: usize = 8;
+
+/// Iterator over tuples of word position and [`Token`].
+///
+/// Words and stop words increase the position by 1,
+/// or by the hard separator gap if they are preceded by a hard separator.
+/// A run of consecutive separators is counted only once,
+/// separators get the position of the word preceding them,
+/// subwords get the position of the word they are part of
+/// and synonyms get the position of the first word they have been expanded from,
+/// increased by their [`Token::synonym_index`] so each multi-word synonym is a sequence of positions.
+///
+/// # Example
+///
+/// ```
+/// use charabia::Tokenize;
+///
+/// let positions: Vec<_> = "The quick \"quick\" fox. Jumps!"
+///     .tokenize()
+///     .with_positions(8)
+///     .filter(|(_, token)| token.is_word())
+///     .map(|(position, token)| (position, token.lemma().to_string()))
+///     .collect();
+///
+/// assert_eq!(
+///     positions,
+///     [
+///         (0, "the".to_string()),
+///         (1, "quick".to_string()),
+///         (2, "quick".to_string()),
+///         (3, "fox".to_string()),
+///         (11, "jumps".to_string()),
+///     ]
+/// );
+/// ```
+pub struct PositionedTokenIter<I> {
+    token_iter: I,
+    hard_separator_gap: usize,
+    /// position of the last word, `None` before the first word.
+    position: Option<usize>,
+    /// kind of the separators found since the last word.
+    separator: Option<SeparatorKind>,
+    /// byte offsets and positions of the last words since the last hard separator,
+    /// only the words that can start the source of a synonym are kept.
+    words: VecDeque<(usize, usize)>,
+    /// number of words of the longest synonym source.
+    max_source_len: usize,
+}
+
+impl<I> PositionedTokenIter<I> {
+    fn new(token_iter: I, hard_separator_gap: usize, max_source_len: usize) -> Self {
+        Self {
+            token_iter,
+            hard_separator_gap,
+            position: None,
+            separator: None,
+            words: VecDeque::with_capacity(max_source_len),
+            max_source_len,
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for PositionedTokenIter<I> {
+    type Item = (usize, Token<'o>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.token_iter.next()?;
+
+        if let Some(kind) = token.separator_kind() {
+            if kind == SeparatorKind::Hard {
+                self.separator = Some(SeparatorKind::Hard);
+                self.words.clear();
+            } else {
+                self.separator.get_or_insert(SeparatorKind::Soft);
+            }
+            return Some((self.position.unwrap_or_default(), token));
+        }
+
+        if token.is_subword {
+            return Some((self.position.unwrap_or_default(), token));
+        }
+
+        if token.is_synonym {
+            let position = self
+                .words
+                .iter()
+                .rev()
+                .find(|(byte_start, _)| *byte_start == token.byte_start)
+                .map(|(_, position)| *position);
+            let position = position.or(self.position).unwrap_or_default() + token.synonym_index;
+            return Some((position, token));
+        }
+
+        let position = match (self.position, self.separator.take()) {
+            (None, _) => 0,
+            (Some(position), Some(SeparatorKind::Hard)) => position + self.hard_separator_gap,
+            (Some(position), _) => position + 1,
+        };
+        self.position = Some(position);
+        if self.max_source_len > 0 {
+            if self.words.len() == self.max_source_len {
+                self.words.pop_front();
+            }
+            self.words.push_back((token.byte_start, position));
+        }
+
+        Some((position, token))
+    }
+}
+
+impl<'o, 'al, 'sw, A> NormalizedTokenIter<'o, 'al, 'sw, A> {
+    /// Attaches to each [`Token`] its word position, see [`PositionedTokenIter`].
+    ///
+    /// # Arguments
+    ///
+    /// * `hard_separator_gap` - the position gap between two words separated by a hard separator,
+    ///   see [`DEFAULT_HARD_SEPARATOR_GAP`].
+    pub fn with_positions(self, hard_separator_gap: usize) -> PositionedTokenIter<Self> {
+        PositionedTokenIter::new(self, hard_separator_gap, 0)
+    }
+}
+
+impl<'o, 'al, 'sw, 'sy, A> SynonymTokenIter<'o, 'al, 'sw, 'sy, A> {
+    /// Attaches to each [`Token`] its word position, see [`PositionedTokenIter`].
+    ///
+    /// # Arguments
+    ///
+    /// * `hard_separator_gap` - the position gap between two words separated by a hard separator,
+    ///   see [`DEFAULT_HARD_SEPARATOR_GAP`].
+    pub fn with_positions(self, hard_separator_gap: usize) -> PositionedTokenIter<Self> {
+        let max_source_len = self.max_source_len();
+        PositionedTokenIter::new(self, hard_separator_gap, max_source_len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::synonym::Synonyms;
+    use crate::Tokenize;
+
+    fn positions<'o>(
+        tokens: impl Iterator<Item = (usize, crate::Token<'o>)>,
+    ) -> Vec<(usize, String)> {
+        tokens.map(|(position, token)| (position, token.lemma().to_string())).collect()
+    }
+
+    #[test]
+    fn separator_runs() {
+        // the run of separators between `hello` and `world` is counted only once.
+        let tokens = "hello ... world!! , bye".tokenize().with_positions(8);
+        assert_eq!(
+            positions(tokens),
+            [
+                (0, "hello".to_string()),
+                (0, " ".to_string()),
+                (0, ".".to_string()),
+                (0, ".".to_string()),
+                (0, ".".to_string()),
+                (0, " ".to_string()),
+                (8, "world".to_string()),
+                (8, "!".to_string()),
+                (8, "!".to_string()),
+                (8, " ".to_string()),
+                (8, ",".to_string()),
+                (8, " ".to_string()),
+                (16, "bye".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn leading_separators() {
+        let tokens = ". hello world".tokenize().with_positions(3);
+        let words: Vec<_> = positions(tokens).into_iter().filter(|(_, l)| l != " ").collect();
+        assert_eq!(
+            words,
+            [(0, ".".to_string()), (0, "hello".to_string()), (1, "world".to_string())]
+        );
+    }
+
+    #[test]
+    fn synonyms() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("new york", "nyc").insert("new york", "big apple");
+
+        let tokens = "in new york".tokenize().synonyms(&synonyms).with_positions(8);
+        let words: Vec<_> = positions(tokens).into_iter().filter(|(_, l)| l != " ").collect();
+        assert_eq!(
+            words,
+            [
+                (0, "in".to_string()),
+                (1, "new".to_string()),
+                (2, "york".to_string()),
+                (1, "nyc".to_string()),
+                (1, "big".to_string()),
+                (2, "apple".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn bounded_synonym_window() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("new york", "nyc");
+
+        // a long text without any hard separator.
+        let text = "a ".repeat(1000) + "new york";
+        let text = text.as_str();
+        let mut tokens = text.tokenize().synonyms(&synonyms).with_positions(8);
+        let mut max_words = 0;
+        let mut nyc = None;
+        while let Some((position, token)) = tokens.next() {
+            max_words = max_words.max(tokens.words.len());
+            if token.lemma() == "nyc" {
+                nyc = Some(position);
+            }
+        }
+
+        assert_eq!(max_words, 2);
+        assert_eq!(nyc, Some(1000));
+    }
+}
@@ -0,0 +1,183 @@
+use std::ops::Range;
+
+use crate::{Token, Tokenizer};
+
+/// Structured query created by [`Tokenizer::tokenize_query`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query<'o> {
+    /// words to match, in the order of the query.
+    pub terms: Vec<QueryTerm<'o>>,
+    /// quoted phrases, each phrase contains the words that must be matched consecutively.
+    pub phrases: Vec<Vec<Token<'o>>>,
+    /// words preceded by a `-` that must not be matched.
+    pub negated: Vec<Token<'o>>,
+    /// quoted phrases preceded by a `-` that must not be matched.
+    pub negated_phrases: Vec<Vec<Token<'o>>>,
+}
+
+/// A word of a [`Query`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryTerm<'o> {
+    pub token: Token<'o>,
+    /// true if the term should also match the words starting with it,
+    /// only the last word of the query can be a prefix.
+    pub is_prefix: bool,
+}
+
+impl QueryTerm<'_> {
+    /// Returns true if the term matches the provided word of a document,
+    /// meaning that one of the [`lemmas`](Token::lemmas) of the term is equal to one of the lemmas of the word,
+    /// or is a prefix of it if the term is a prefix.
+    ///
+    /// The [`Token::alternatives`] are compared like the lemmas,
+    /// so "muller" matches "Müller" expanded into "mueller" by the German expansion.
+    pub fn matches(&self, word: &Token) -> bool {
+        self.token.lemmas().any(|term| {
+            word.lemmas().any(|lemma| match self.is_prefix {
+                true => lemma.starts_with(term),
+                false => lemma == term,
+            })
+        })
+    }
+}
+
+impl<A: AsRef<[u8]>> Tokenizer<'_, '_, A> {
+    /// Tokenizes a user query.
+    ///
+    /// The query is tokenized as a whole with the same segmentation and normalization as the documents,
+    /// then the words are dispatched in the [`Query`]:
+    /// - the words between double quotes are grouped in a phrase, a quote that is never closed ends at the end of the query,
+    /// - a word or a phrase directly preceded by a `-`, at the beginning of the query or after a whitespace, is negated,
+    /// - the other words are terms, the last one is a prefix if nothing follows it in the query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let tokenizer = TokenizerBuilder::default().build();
+    /// let query = tokenizer.tokenize_query(r#"Hello "New York" -snow Citi"#);
+    ///
+    /// let terms: Vec<_> = query.terms.iter().map(|t| (t.token.lemma(), t.is_prefix)).collect();
+    /// assert_eq!(terms, [("hello", false), ("citi", true)]);
+    ///
+    /// let phrase: Vec<_> = query.phrases[0].iter().map(|t| t.lemma()).collect();
+    /// assert_eq!(phrase, ["new", "york"]);
+    ///
+    /// assert_eq!(query.negated[0].lemma(), "snow");
+    ///
+    /// let query = tokenizer.tokenize_query(r#"-"New York""#);
+    /// let phrase: Vec<_> = query.negated_phrases[0].iter().map(|t| t.lemma()).collect();
+    /// assert_eq!(phrase, ["new", "york"]);
+    /// ```
+    pub fn tokenize_query<'o>(&self, query: &'o str) -> Query<'o> {
+        let phrases = phrase_ranges(query);
+        let mut result = Query::default();
+        let mut current_phrase = None;
+
+        for token in self.tokenize(query) {
+            // the subwords are already searched through the word they are part of.
+            if token.is_separator() || token.is_subword() {
+                continue;
+            }
+
+            match phrases.iter().position(|range| range.contains(&token.byte_start)) {
+                Some(phrase) => {
+                    // the opening quote directly follows the `-` of a negated phrase.
+                    let phrases = match is_negated(query, phrases[phrase].start - 1) {
+                        true => &mut result.negated_phrases,
+                        false => &mut result.phrases,
+                    };
+                    if current_phrase != Some(phrase) {
+                        current_phrase = Some(phrase);
+                        phrases.push(Vec::new());
+                    }
+                    phrases.last_mut().unwrap().push(token);
+                }
+                None if is_negated(query, token.byte_start) => result.negated.push(token),
+                None => result.terms.push(QueryTerm { token, is_prefix: false }),
+            }
+        }
+
+        // the last word is a prefix only if the user may still be typing it.
+        if let Some(last) = result.terms.last_mut() {
+            let is_last_word = result
+                .phrases
+                .iter()
+                .chain(&result.negated_phrases)
+                .flatten()
+                .chain(&result.negated)
+                .all(|t| t.byte_start < last.token.byte_start);
+            last.is_prefix = is_last_word && last.token.byte_end == query.len();
+        }
+
+        result
+    }
+}
+
+/// Returns the byte ranges of the text between double quotes.
+fn phrase_ranges(query: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut quotes = query.match_indices('"').map(|(index, _)| index);
+    while let Some(start) = quotes.next() {
+        let end = quotes.next().unwrap_or(query.len());
+        ranges.push(start + 1..end);
+    }
+
+    ranges
+}
+
+/// Returns true if the text starting at `offset` is preceded by a `-` that doesn't follow a word.
+fn is_negated(query: &str, offset: usize) -> bool {
+    match query[..offset].strip_suffix('-') {
+        Some(before) => match before.chars().next_back() {
+            Some(c) => c.is_whitespace(),
+            None => true,
+        },
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::TokenizerBuilder;
+
+    #[test]
+    fn prefix() {
+        let tokenizer = TokenizerBuilder::default().build();
+
+        let query = tokenizer.tokenize_query("the quick bro");
+        let prefixes: Vec<_> = query.terms.iter().map(|t| t.is_prefix).collect();
+        assert_eq!(prefixes, [false, false, true]);
+
+        // the last word is finished.
+        for text in
+            ["the quick brown ", "the quick brown!", r#"the quick "brown"#, "the quick -brown"]
+        {
+            let query = tokenizer.tokenize_query(text);
+            assert!(query.terms.iter().all(|t| !t.is_prefix), "{text}");
+        }
+    }
+
+    #[test]
+    fn phrases_and_negation() {
+        let tokenizer = TokenizerBuilder::default().build();
+
+        let query = tokenizer.tokenize_query(r#"-"a b" e-mail "c d" -e x-"g" "f"#);
+        let terms: Vec<_> = query.terms.iter().map(|t| t.token.lemma()).collect();
+        assert_eq!(terms, ["e", "mail", "x"]);
+        let phrases: Vec<Vec<_>> =
+            query.phrases.iter().map(|p| p.iter().map(|t| t.lemma()).collect()).collect();
+        assert_eq!(phrases, [vec!["c", "d"], vec!["g"], vec!["f"]]);
+        let negated: Vec<_> = query.negated.iter().map(|t| t.lemma()).collect();
+        assert_eq!(negated, ["e"]);
+        // a phrase preceded by a `-` is negated, the `-` of "x-" follows a word.
+        let negated_phrases: Vec<Vec<_>> =
+            query.negated_phrases.iter().map(|p| p.iter().map(|t| t.lemma()).collect()).collect();
+        assert_eq!(negated_phrases, [vec!["a", "b"]]);
+
+        // offsets are the ones of the original query.
+        let token = &query.phrases[0][1];
+        assert_eq!((token.byte_start, token.byte_end), (17, 18));
+    }
+}
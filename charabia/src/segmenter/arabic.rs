@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::Segmenter;
+
+/// Clitic prefixes split from the words, from the longest to the shortest,
+/// with the minimum number of letters of the remaining stem.
+const PREFIXES: &[(&[&str], usize)] = &[
+    (&["و", "ب", "ال"], 2),
+    (&["ف", "ب", "ال"], 2),
+    (&["و", "ك", "ال"], 2),
+    (&["ف", "ك", "ال"], 2),
+    (&["و", "ل", "ل"], 2),
+    (&["ف", "ل", "ل"], 2),
+    (&["و", "ال"], 2),
+    (&["ف", "ال"], 2),
+    (&["ب", "ال"], 2),
+    (&["ك", "ال"], 2),
+    (&["ل", "ل"], 2),
+    (&["ال"], 2),
+    (&["و"], 3),
+];
+
+/// Arabic specialized [`Segmenter`].
+///
+/// This Segmenter uses [`UnicodeSegmentation`] internally to segment the provided text,
+/// then strips the conjunctions (و، ف), the prepositions (ب، ك، ل) and the article (ال) attached to the words
+/// following light stemming rules: a clitic is only stripped if the remaining stem is long enough
+/// and the prepositions are only stripped when followed by the article.
+/// The words are kept whole and their clitics and stem are emitted as subwords,
+/// for example, "والكتاب" is segmented into "والكتاب" having the subwords "و", "ال" and "كتاب".
+///
+/// The segmenter is only used on the text detected as Arabic,
+/// the other languages written in the Arabic script, like Persian or Urdu, are segmented by the default segmenter.
+pub struct ArabicSegmenter;
+
+impl Segmenter for ArabicSegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        Box::new(s.split_word_bounds())
+    }
+
+    fn subwords(&self, segment: &str) -> Vec<Range<usize>> {
+        let parts = split_clitics(segment);
+        if parts.len() < 2 {
+            return Vec::new();
+        }
+
+        let mut start = 0;
+        parts
+            .into_iter()
+            .map(|part| {
+                let range = Range { start, end: start + part.len() };
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+}
+
+/// Splits the clitic prefixes of the provided word, the last part being the stem.
+fn split_clitics(word: &str) -> Vec<&str> {
+    for (clitics, min_stem_letters) in PREFIXES {
+        let mut parts = Vec::with_capacity(clitics.len() + 1);
+        let mut rest = word;
+        for clitic in clitics.iter() {
+            match clitic_len(rest, clitic) {
+                Some(len) => {
+                    let (part, stem) = rest.split_at(len);
+                    parts.push(part);
+                    rest = stem;
+                }
+                None => break,
+            }
+        }
+
+        if parts.len() == clitics.len() && letter_count(rest) >= *min_stem_letters {
+            parts.push(rest);
+            return parts;
+        }
+    }
+
+    vec![word]
+}
+
+/// Returns the byte length of the clitic at the start of the word,
+/// the diacritics and the tatweels written on its letters are part of the clitic.
+fn clitic_len(word: &str, clitic: &str) -> Option<usize> {
+    let mut len = 0;
+    for letter in clitic.chars() {
+        let rest = word[len..].strip_prefix(letter)?;
+        let marks: usize = rest.chars().take_while(|c| !is_letter(*c)).map(char::len_utf8).sum();
+        len = word.len() - rest.len() + marks;
+    }
+
+    Some(len)
+}
+
+/// Returns the number of letters of the word, ignoring the diacritics and the tatweels.
+fn letter_count(word: &str) -> usize {
+    word.chars().filter(|c| is_letter(*c)).count()
+}
+
+fn is_letter(c: char) -> bool {
+    !matches!(c, '\u{064B}'..='\u{065F}' | '\u{0670}' | 'ـ')
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "والكتاب على الطاولة، وذهب الولد إلى المدرسة بالسيارة.";
+
+    const SEGMENTED: &[&str] = &[
+        "والكتاب",
+        " ",
+        "على",
+        " ",
+        "الطاولة",
+        "،",
+        " ",
+        "وذهب",
+        " ",
+        "الولد",
+        " ",
+        "إلى",
+        " ",
+        "المدرسة",
+        " ",
+        "بالسيارة",
+        ".",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "والكتاب",
+        "و",
+        "ال",
+        "كتاب",
+        " ",
+        "على",
+        " ",
+        "الطاولة",
+        "ال",
+        "طاولة",
+        "،",
+        " ",
+        "وذهب",
+        "و",
+        "ذهب",
+        " ",
+        "الولد",
+        "ال",
+        "ولد",
+        " ",
+        "الى",
+        " ",
+        "المدرسة",
+        "ال",
+        "مدرسة",
+        " ",
+        "بالسيارة",
+        "ب",
+        "ال",
+        "سيارة",
+        ".",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(ArabicSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Arabic, Language::Ara);
+
+    #[test]
+    fn light_stemming_rules() {
+        // the diacritics stay on their letter.
+        assert_eq!(split_clitics("وَالْكِتَابُ"), ["وَ", "الْ", "كِتَابُ"]);
+        assert_eq!(split_clitics("فللبيت"), ["ف", "ل", "ل", "بيت"]);
+        // the prepositions are only split when followed by the article.
+        assert_eq!(split_clitics("بيت"), ["بيت"]);
+        assert_eq!(split_clitics("كتاب"), ["كتاب"]);
+        // the stem must be long enough.
+        assert_eq!(split_clitics("ولد"), ["ولد"]);
+        assert_eq!(split_clitics("ال"), ["ال"]);
+    }
+
+    #[test]
+    fn offsets() {
+        let text = "قرأ والكتاب";
+        let tokens: Vec<_> = text.segment().collect();
+        let offsets: Vec<_> = tokens.iter().map(|t| (t.byte_start, t.byte_end)).collect();
+        assert_eq!(offsets, [(0, 6), (6, 7), (7, 21), (7, 9), (9, 13), (13, 21)]);
+        assert!(tokens[3..].iter().all(|token| token.is_subword()));
+        let subwords: Vec<_> =
+            tokens[3..].iter().map(|t| &text[t.byte_start..t.byte_end]).collect();
+        assert_eq!(subwords, ["و", "ال", "كتاب"]);
+    }
+
+    #[test]
+    fn other_languages() {
+        // Persian is written in the Arabic script but doesn't have the Arabic clitics.
+        let text = "من الان در خانه هستم و کتاب می‌خوانم";
+        let tokens: Vec<_> = text.segment().collect();
+        assert_eq!(tokens[0].language, Some(Language::Pes));
+        assert!(tokens.iter().all(|token| !token.is_subword()));
+    }
+}
@@ -6,7 +6,7 @@ pub(crate) trait CamelCaseSegmentation {
     /// For instance, "camelCase" is split into ["camel", "Case"].
     /// A camelCase boundary constitutes a lowercase letter directly followed by an uppercase letter
     /// where lower and uppercase letters are defined by the corresponding Unicode General Categories.
-    fn split_camel_case_bounds(&self) -> CamelCaseParts;
+    fn split_camel_case_bounds(&self) -> CamelCaseParts<'_>;
 }
 
 pub(crate) struct CamelCaseParts<'t> {
@@ -19,7 +19,7 @@ enum State<'t> {
 }
 
 impl CamelCaseSegmentation for str {
-    fn split_camel_case_bounds(&self) -> CamelCaseParts {
+    fn split_camel_case_bounds(&self) -> CamelCaseParts<'_> {
         CamelCaseParts { state: State::InProgress { remainder: self } }
     }
 }
@@ -0,0 +1,231 @@
+use std::iter::once;
+use std::ops::Range;
+
+use fst::raw::Fst;
+use once_cell::sync::Lazy;
+
+use super::utils::find_prefixes;
+use super::{LatinSegmenter, Segmenter};
+
+/// Minimum number of characters of a part of a compound word.
+const MIN_PART_CHARS: usize = 3;
+
+static DEU_WORDS: Lazy<Fst<&[u8]>> =
+    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/compound/deu.fst")[..]).unwrap());
+
+/// German compound word [`Segmenter`].
+///
+/// This Segmenter segments the provided text like the [`LatinSegmenter`]
+/// and splits the compound words into their parts using a dictionary encoded as an FST:
+/// the longest dictionary words are tried first and the parts can be joined by the linking morphemes of the language,
+/// like the "s" of "Arbeitsamt" which is split into "Arbeit" and "amt".
+/// A word contained in the dictionary is never split.
+///
+/// Only German is supported: the other compounding languages need a real word list to be decomposed.
+///
+/// Dictionary source: the word lists in `dictionaries/txt/compound`.
+pub struct CompoundSegmenter {
+    words: &'static Fst<&'static [u8]>,
+    linking_morphemes: &'static [&'static str],
+}
+
+impl CompoundSegmenter {
+    /// German compound segmenter.
+    pub fn german() -> Self {
+        Self { words: &DEU_WORDS, linking_morphemes: &["s", "es", "n", "en", "e", "er", "ens"] }
+    }
+
+    /// Pushes in `parts` the ranges of the dictionary words composing `word` from `start`,
+    /// returns false if `word` can't be entirely decomposed.
+    ///
+    /// The start offsets that can't be decomposed are marked in `failed`, so they are never tried twice.
+    fn decompose(
+        &self,
+        word: &str,
+        start: usize,
+        parts: &mut Vec<Range<usize>>,
+        failed: &mut [bool],
+    ) -> bool {
+        if failed[start] {
+            return false;
+        }
+
+        let rest = &word[start..];
+        // the longest parts are tried first.
+        for len in find_prefixes(self.words, rest.as_bytes()).into_iter().rev() {
+            if rest[..len].chars().count() < MIN_PART_CHARS {
+                break;
+            }
+
+            let end = start + len;
+            parts.push(start..end);
+            if end == word.len() {
+                return true;
+            }
+
+            for link in once("").chain(self.linking_morphemes.iter().copied()) {
+                let next = end + link.len();
+                if next < word.len()
+                    && word[end..].starts_with(link)
+                    && self.decompose(word, next, parts, failed)
+                {
+                    return true;
+                }
+            }
+            parts.pop();
+        }
+
+        failed[start] = true;
+        false
+    }
+}
+
+impl Segmenter for CompoundSegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        LatinSegmenter.segment_str(s)
+    }
+
+    fn subwords(&self, segment: &str) -> Vec<Range<usize>> {
+        let word = segment.to_lowercase();
+        // the parts are found in the lowercased word, its offsets must be the original ones.
+        if word.len() != segment.len() || self.words.contains_key(&word) {
+            return Vec::new();
+        }
+
+        let mut parts = Vec::new();
+        let mut failed = vec![false; word.len()];
+        if self.decompose(&word, 0, &mut parts, &mut failed) && parts.len() > 1 {
+            parts
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use fst::Set;
+
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str =
+        "Die Donaudampfschifffahrt beginnt morgen, das Arbeitsamt schließt heute den Kindergarten.";
+
+    const SEGMENTED: &[&str] = &[
+        "Die",
+        " ",
+        "Donaudampfschifffahrt",
+        " ",
+        "beginnt",
+        " ",
+        "morgen",
+        ",",
+        " ",
+        "das",
+        " ",
+        "Arbeitsamt",
+        " ",
+        "schließt",
+        " ",
+        "heute",
+        " ",
+        "den",
+        " ",
+        "Kindergarten",
+        ".",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "die",
+        " ",
+        "donaudampfschifffahrt",
+        "donau",
+        "dampf",
+        "schiff",
+        "fahrt",
+        " ",
+        "beginnt",
+        " ",
+        "morgen",
+        ",",
+        " ",
+        "das",
+        " ",
+        "arbeitsamt",
+        "arbeit",
+        "amt",
+        " ",
+        "schließt",
+        " ",
+        "heute",
+        " ",
+        "den",
+        " ",
+        "kindergarten",
+        "kind",
+        "garten",
+        ".",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(
+        CompoundSegmenter::german(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Latin,
+        Language::Deu
+    );
+
+    #[test]
+    fn subwords() {
+        for (segmenter, word, parts) in [
+            (CompoundSegmenter::german(), "Hundehütte", &["Hund", "hütte"][..]),
+            (CompoundSegmenter::german(), "Geburtstag", &["Geburt", "tag"]),
+            (CompoundSegmenter::german(), "Zahnarzt", &["Zahn", "arzt"]),
+            (CompoundSegmenter::german(), "Wohnungstür", &["Wohnung", "tür"]),
+            (CompoundSegmenter::german(), "Feuerwehrmann", &["Feuerwehr", "mann"]),
+            (CompoundSegmenter::german(), "Bahnhof", &[]),
+            (CompoundSegmenter::german(), "Haus", &[]),
+        ] {
+            let subwords: Vec<_> =
+                segmenter.subwords(word).into_iter().map(|range| &word[range]).collect();
+            assert_eq!(subwords, parts, "{word}");
+        }
+    }
+
+    #[test]
+    fn long_words() {
+        // every start offset is only decomposed once, a long word that can't be decomposed is quickly rejected.
+        let word = format!("{}xq", "hausesamtes".repeat(200));
+        assert!(CompoundSegmenter::german().subwords(&word).is_empty());
+
+        let word = "hausamt".repeat(20);
+        assert_eq!(CompoundSegmenter::german().subwords(&word).len(), 40);
+    }
+
+    #[test]
+    fn subword_offsets() {
+        let text = "Ein Arbeitsamt";
+        let tokens: Vec<_> = text.segment().filter(|token| token.is_subword()).collect();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!((tokens[0].byte_start, tokens[0].byte_end), (4, 10));
+        assert_eq!((tokens[1].byte_start, tokens[1].byte_end), (11, 14));
+        assert_eq!(&text[tokens[1].byte_start..tokens[1].byte_end], "amt");
+    }
+
+    #[test]
+    fn shipped_dictionary() {
+        let txt = include_str!("../../dictionaries/txt/compound/deu.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        words.dedup();
+        let set = Set::from_iter(words).unwrap();
+        assert_eq!(
+            set.as_fst().as_bytes(),
+            include_bytes!("../../dictionaries/fst/compound/deu.fst")
+        );
+    }
+}
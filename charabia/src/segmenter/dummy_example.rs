@@ -57,7 +57,7 @@ mod test {
 }
 
 // Include the newly implemented Segmenter in the tokenization pipeline:
-//	   - assign Segmenter to a Script and a Language by adding it in `SEGMENTERS` in `segmenter/mod.rs`
+//	   - assign Segmenter to a Script and a Language by adding it in `SHARED_SEGMENTERS` in `segmenter/mod.rs`
 //	   - check if it didn't break any test or benhchmark
 
 // Your Segmenter will now be used on texts of the assigned Script and Language. Thank you for your contribution, and congratulation! 🎉
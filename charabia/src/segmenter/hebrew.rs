@@ -1,16 +1,84 @@
+#[cfg(feature = "hebrew-prefixes")]
+use std::ops::Range;
+
+#[cfg(feature = "hebrew-prefixes")]
+use fst::raw::Fst;
+#[cfg(feature = "hebrew-prefixes")]
+use once_cell::sync::Lazy;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::Segmenter;
 
+/// Letters that can be prefixed to a word: and, the, in, to, from, that, like.
+#[cfg(feature = "hebrew-prefixes")]
+const PREFIX_LETTERS: &[char] = &['ו', 'ה', 'ב', 'ל', 'מ', 'ש', 'כ'];
+
+/// Maximum number of prefix letters split from a word, like "וכשה".
+#[cfg(feature = "hebrew-prefixes")]
+const MAX_PREFIX_LETTERS: usize = 4;
+
+#[cfg(feature = "hebrew-prefixes")]
+static WORDS_FST: Lazy<Fst<&[u8]>> =
+    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/hebrew/words.fst")[..]).unwrap());
+
 /// Hebrew specialized [`Segmenter`].
 ///
 /// This Segmenter uses [`UnicodeSegmentation`] internally to segment the provided text.
+/// With the `hebrew-prefixes` feature, the prefix letters (ו, ה, ב, ל, מ, ש, כ) of a word are split using a lexicon
+/// encoded as an FST: a word missing from the lexicon whose stem is in the lexicon, like "והבית",
+/// gets its stem, "בית", as a subword, the words of the lexicon starting with a prefix letter,
+/// like "שלום" or "מספר", are never split.
+///
+/// Lexicon source: the word list in `dictionaries/txt/hebrew`.
 pub struct HebrewSegmenter;
 
 impl Segmenter for HebrewSegmenter {
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
         Box::new(s.split_word_bounds().flat_map(|lemma| lemma.split_inclusive('\'')))
     }
+
+    #[cfg(feature = "hebrew-prefixes")]
+    fn subwords(&self, segment: &str) -> Vec<Range<usize>> {
+        if !segment.starts_with(PREFIX_LETTERS) {
+            return Vec::new();
+        }
+
+        // the lexicon doesn't contain the points (niqqud) and cantillation marks.
+        let letters: Vec<_> = segment.char_indices().filter(|(_, c)| !is_mark(*c)).collect();
+        let word: String = letters.iter().map(|(_, c)| c).collect();
+        if WORDS_FST.contains_key(&word) {
+            return Vec::new();
+        }
+
+        // the shortest prefix giving a stem of the lexicon is chosen.
+        let prefixes =
+            letters.iter().take(MAX_PREFIX_LETTERS).enumerate().take_while(|(i, (_, c))| {
+                // the conjunction ו can only be the first prefix.
+                PREFIX_LETTERS.contains(c) && (*i == 0 || *c != 'ו')
+            });
+        for (i, _) in prefixes {
+            let stem = &letters[i + 1..];
+            if stem.len() >= 2
+                && WORDS_FST.contains_key(stem.iter().map(|(_, c)| *c).collect::<String>())
+            {
+                return vec![Range { start: stem[0].0, end: segment.len() }];
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+/// Returns true if the character is a Hebrew point or cantillation mark.
+#[cfg(feature = "hebrew-prefixes")]
+fn is_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0591}'..='\u{05BD}'
+        | '\u{05BF}'
+        | '\u{05C1}'..='\u{05C2}'
+        | '\u{05C4}'..='\u{05C5}'
+        | '\u{05C7}'
+    )
 }
 
 #[cfg(test)]
@@ -56,12 +124,18 @@ mod test {
     ];
     const TOKENIZED: &[&str] = &[
         "השועל",
+        #[cfg(feature = "hebrew-prefixes")]
+        "שועל",
         " ",
         "המהיר",
+        #[cfg(feature = "hebrew-prefixes")]
+        "מהיר",
         " ",
         "(",
         "״",
         "החום",
+        #[cfg(feature = "hebrew-prefixes")]
+        "חום",
         "״",
         ")",
         " ",
@@ -88,8 +162,78 @@ mod test {
         "-",
         " ",
         "בחוץ",
+        #[cfg(feature = "hebrew-prefixes")]
+        "חוץ",
         "!",
     ];
 
     test_segmenter!(HebrewSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Hebrew, Language::Heb);
+
+    #[cfg(feature = "hebrew-prefixes")]
+    #[test]
+    fn prefix_letters() {
+        let subwords = |word: &'static str| -> Vec<&str> {
+            HebrewSegmenter.subwords(word).into_iter().map(|range| &word[range]).collect()
+        };
+
+        assert_eq!(subwords("והבית"), ["בית"]);
+        assert_eq!(subwords("וכשהילד"), ["ילד"]);
+        assert_eq!(subwords("וְהַבַּיִת"), ["בַּיִת"]);
+        // the words of the lexicon are never split.
+        assert_eq!(subwords("שלום"), [] as [&str; 0]);
+        assert_eq!(subwords("משפחה"), [] as [&str; 0]);
+        // the stem must be in the lexicon.
+        assert_eq!(subwords("הכלבלב"), [] as [&str; 0]);
+        // the conjunction can only be the first prefix.
+        assert_eq!(subwords("הובית"), [] as [&str; 0]);
+        // the words starting with a prefix letter are kept whole, "מספר" isn't "מ" + "ספר".
+        assert_eq!(subwords("מספר"), [] as [&str; 0]);
+        assert_eq!(subwords("שמים"), [] as [&str; 0]);
+        assert_eq!(subwords("בוקר"), [] as [&str; 0]);
+        assert_eq!(subwords("כוכב"), [] as [&str; 0]);
+    }
+
+    #[cfg(not(feature = "hebrew-prefixes"))]
+    #[test]
+    fn no_prefix_letters() {
+        // the prefix letters are only split with the `hebrew-prefixes` feature.
+        let tokens: Vec<_> = "והבית מספר שמים".tokenize().filter(|token| token.is_word()).collect();
+        assert!(tokens.iter().all(|token| !token.is_subword));
+        assert_eq!(tokens.len(), 3);
+    }
+
+    #[cfg(feature = "hebrew-prefixes")]
+    #[test]
+    fn shipped_lexicon() {
+        let txt = include_str!("../../dictionaries/txt/hebrew/words.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        words.dedup();
+        let set = fst::Set::from_iter(words).unwrap();
+        assert_eq!(
+            set.as_fst().as_bytes(),
+            include_bytes!("../../dictionaries/fst/hebrew/words.fst")
+        );
+    }
+
+    #[cfg(feature = "hebrew-prefixes")]
+    #[test]
+    fn stem_position() {
+        let positions: Vec<_> = "ראיתי את והבית"
+            .tokenize()
+            .with_positions(8)
+            .filter(|(_, token)| token.is_word())
+            .map(|(position, token)| (position, token.lemma().to_string(), token.byte_start))
+            .collect();
+
+        assert_eq!(
+            positions,
+            [
+                (0, "ראיתי".to_string(), 0),
+                (1, "את".to_string(), 11),
+                (2, "והבית".to_string(), 16),
+                (2, "בית".to_string(), 20),
+            ]
+        );
+    }
 }
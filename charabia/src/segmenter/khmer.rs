@@ -0,0 +1,65 @@
+// Import `Segmenter` trait.
+use fst::raw::Fst;
+use once_cell::sync::Lazy;
+
+use crate::segmenter::utils::FstSegmenter;
+use crate::segmenter::Segmenter;
+
+/// Khmer specialized [`Segmenter`].
+///
+/// This Segmenter uses a dictionary encoded as an FST to segment the provided text,
+/// the text missing from the dictionary is split into grapheme clusters.
+/// Dictionary source: the word list in `dictionaries/txt/khmer`.
+pub struct KhmerSegmenter;
+
+static WORDS_FST: Lazy<Fst<&[u8]>> =
+    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/khmer/words.fst")[..]).unwrap());
+
+static FST_SEGMENTER: Lazy<FstSegmenter> = Lazy::new(|| FstSegmenter::new(&WORDS_FST));
+
+impl Segmenter for KhmerSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        FST_SEGMENTER.segment_str(to_segment)
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use fst::Set;
+
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "ខ្ញុំស្រឡាញ់ប្រទេសកម្ពុជា ខ្ញុំរៀនភាសាខ្មែរនៅភ្នំពេញ";
+
+    const SEGMENTED: &[&str] =
+        &["ខ្ញុំ", "ស្រឡាញ់", "ប្រទេស", "កម្ពុជា", " ", "ខ្ញុំ", "រៀន", "ភាសា", "ខ្មែរ", "នៅ", "ភ្នំពេញ"];
+
+    const TOKENIZED: &[&str] =
+        &["ខ្ញុំ", "ស្រឡាញ់", "ប្រទេស", "កម្ពុជា", " ", "ខ្ញុំ", "រៀន", "ភាសា", "ខ្មែរ", "នៅ", "ភ្នំពេញ"];
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(KhmerSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Khmer, Language::Khm);
+
+    #[test]
+    fn out_of_vocabulary() {
+        let segmented: Vec<_> = KhmerSegmenter.segment_str("សួស្តីពិភពលោក").collect();
+        assert_eq!(segmented, ["សួស្តី", "ពិភពលោក"]);
+
+        // a word missing from the dictionary is split in clusters keeping the signs with their consonant.
+        let segmented: Vec<_> = KhmerSegmenter.segment_str("ហ្វេសប៊ុក").collect();
+        assert_eq!(segmented, ["ហ្វេ", "ស", "ប៊ុ", "ក"]);
+    }
+
+    #[test]
+    fn shipped_dictionary() {
+        let txt = include_str!("../../dictionaries/txt/khmer/words.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        words.dedup();
+        let set = Set::from_iter(words).unwrap();
+        assert_eq!(
+            set.as_fst().as_bytes(),
+            include_bytes!("../../dictionaries/fst/khmer/words.fst")
+        );
+    }
+}
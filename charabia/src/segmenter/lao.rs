@@ -0,0 +1,62 @@
+// Import `Segmenter` trait.
+use fst::raw::Fst;
+use once_cell::sync::Lazy;
+
+use crate::segmenter::utils::FstSegmenter;
+use crate::segmenter::Segmenter;
+
+/// Lao specialized [`Segmenter`].
+///
+/// This Segmenter uses a dictionary encoded as an FST to segment the provided text,
+/// the text missing from the dictionary is split into grapheme clusters.
+/// Dictionary source: the word list in `dictionaries/txt/lao`.
+pub struct LaoSegmenter;
+
+static WORDS_FST: Lazy<Fst<&[u8]>> =
+    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/lao/words.fst")[..]).unwrap());
+
+static FST_SEGMENTER: Lazy<FstSegmenter> = Lazy::new(|| FstSegmenter::new(&WORDS_FST));
+
+impl Segmenter for LaoSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        FST_SEGMENTER.segment_str(to_segment)
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use fst::Set;
+
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "ຂ້ອຍຮັກປະເທດລາວ ຂ້ອຍຮຽນພາສາລາວຢູ່ວຽງຈັນ";
+
+    const SEGMENTED: &[&str] =
+        &["ຂ້ອຍ", "ຮັກ", "ປະເທດ", "ລາວ", " ", "ຂ້ອຍ", "ຮຽນ", "ພາສາ", "ລາວ", "ຢູ່", "ວຽງຈັນ"];
+
+    const TOKENIZED: &[&str] =
+        &["ຂ້ອຍ", "ຮັກ", "ປະເທດ", "ລາວ", " ", "ຂ້ອຍ", "ຮຽນ", "ພາສາ", "ລາວ", "ຢູ່", "ວຽງຈັນ"];
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(LaoSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Lao, Language::Other);
+
+    #[test]
+    fn out_of_vocabulary() {
+        let segmented: Vec<_> = LaoSegmenter.segment_str("ສະບາຍດີທຸກຄົນ").collect();
+        assert_eq!(segmented, ["ສະບາຍດີ", "ທຸກຄົນ"]);
+
+        // a word missing from the dictionary is split in clusters keeping the signs with their consonant.
+        let segmented: Vec<_> = LaoSegmenter.segment_str("ເຟສບຸກ").collect();
+        assert_eq!(segmented, ["ເຟ", "ສ", "ບຸ", "ກ"]);
+    }
+
+    #[test]
+    fn shipped_dictionary() {
+        let txt = include_str!("../../dictionaries/txt/lao/words.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        words.dedup();
+        let set = Set::from_iter(words).unwrap();
+        assert_eq!(set.as_fst().as_bytes(), include_bytes!("../../dictionaries/fst/lao/words.fst"));
+    }
+}
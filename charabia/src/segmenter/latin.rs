@@ -44,5 +44,11 @@ mod test {
         " ", "29.3", "°", "f", "!", " ", "camel", "case",
     ];
 
-    test_segmenter!(LatinSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Latin, Language::Other);
+    // the language of a Latin text is only detected when a Latin segmenter is specialized for a language.
+    #[cfg(feature = "decompounding")]
+    const LANGUAGE: Language = Language::Eng;
+    #[cfg(not(feature = "decompounding"))]
+    const LANGUAGE: Language = Language::Other;
+
+    test_segmenter!(LatinSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Latin, LANGUAGE);
 }
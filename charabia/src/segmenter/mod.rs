@@ -1,37 +1,75 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::iter::{once, Peekable};
+use std::ops::Range;
+use std::sync::Arc;
 
+#[cfg(feature = "arabic")]
+pub use arabic::ArabicSegmenter;
 #[cfg(feature = "chinese")]
 pub use chinese::ChineseSegmenter;
+#[cfg(feature = "decompounding")]
+pub use compound::CompoundSegmenter;
 #[cfg(feature = "hebrew")]
 pub use hebrew::HebrewSegmenter;
 #[cfg(feature = "japanese")]
 pub use japanese::JapaneseSegmenter;
+#[cfg(feature = "khmer")]
+pub use khmer::KhmerSegmenter;
 #[cfg(feature = "korean")]
 pub use korean::KoreanSegmenter;
+#[cfg(feature = "lao")]
+pub use lao::LaoSegmenter;
 pub use latin::LatinSegmenter;
+#[cfg(feature = "myanmar")]
+pub use myanmar::MyanmarSegmenter;
 use once_cell::sync::Lazy;
+pub use protected_terms::ProtectedTerms;
 use slice_group_by::StrGroupBy;
 #[cfg(feature = "thai")]
 pub use thai::ThaiSegmenter;
+#[cfg(feature = "tibetan")]
+pub use tibetan::TibetanSegmenter;
+#[cfg(feature = "vietnamese")]
+pub use vietnamese::VietnameseSegmenter;
 
+use crate::classifier::SeparatorPolicy;
 use crate::detection::{Detect, Language, Script, StrDetection};
-use crate::token::Token;
+use crate::shared::Shared;
+use crate::token::{Token, TokenKind};
 
+#[cfg(feature = "arabic")]
+mod arabic;
 #[cfg(feature = "latin-camelcase")]
 mod camel_case;
 #[cfg(feature = "chinese")]
 mod chinese;
+#[cfg(feature = "decompounding")]
+mod compound;
 #[cfg(feature = "hebrew")]
 mod hebrew;
 #[cfg(feature = "japanese")]
 mod japanese;
+#[cfg(feature = "khmer")]
+mod khmer;
 #[cfg(feature = "korean")]
 mod korean;
+#[cfg(feature = "lao")]
+mod lao;
 mod latin;
+#[cfg(feature = "myanmar")]
+mod myanmar;
+mod protected_terms;
 #[cfg(feature = "thai")]
 mod thai;
+#[cfg(feature = "tibetan")]
+mod tibetan;
 mod utils;
+#[cfg(feature = "vietnamese")]
+mod vietnamese;
+
+/// Map of [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`].
+pub type SegmenterMap = HashMap<(Script, Language), Arc<dyn Segmenter>>;
 
 /// List of used [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`].
 ///
@@ -44,25 +82,66 @@ mod utils;
 /// A segmenter assigned to `Language::Other` is considered as the default `Segmenter` for any `Language` that uses the assigned `Script`.
 /// For example, [`LatinSegmenter`] is assigned to `(Script::Latin, Language::Other)`,
 /// meaning that `LatinSegmenter` is the default `Segmenter` for any `Language` that uses `Latin` `Script`.
+///
+/// This list is the default segmenter table of a [`Tokenizer`](crate::Tokenizer),
+/// see [`TokenizerBuilder::segmenter`](crate::TokenizerBuilder::segmenter) to add or replace a `Segmenter`.
+///
+/// The segmenters are the ones of the internal table shared by the tokenizers, each one wrapped in a `Box`.
 pub static SEGMENTERS: Lazy<HashMap<(Script, Language), Box<dyn Segmenter>>> = Lazy::new(|| {
+    SHARED_SEGMENTERS
+        .iter()
+        .map(|(key, segmenter)| (*key, Box::new(segmenter.clone()) as Box<dyn Segmenter>))
+        .collect()
+});
+
+/// Same as [`SEGMENTERS`] but the segmenters can be shared by the segmenter tables of the tokenizers,
+/// a new `Segmenter` must be assigned to its `Script` and `Language` here.
+pub(crate) static SHARED_SEGMENTERS: Lazy<SegmenterMap> = Lazy::new(|| {
     vec![
         // latin segmenter
-        ((Script::Latin, Language::Other), Box::new(LatinSegmenter) as Box<dyn Segmenter>),
+        ((Script::Latin, Language::Other), Arc::new(LatinSegmenter) as Arc<dyn Segmenter>),
+        // german compound segmenter
+        #[cfg(feature = "decompounding")]
+        (
+            (Script::Latin, Language::Deu),
+            Arc::new(CompoundSegmenter::german()) as Arc<dyn Segmenter>,
+        ),
         // chinese segmenter
         #[cfg(feature = "chinese")]
-        ((Script::Cj, Language::Cmn), Box::new(ChineseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Language::Cmn), Arc::new(ChineseSegmenter) as Arc<dyn Segmenter>),
+        // arabic segmenter
+        #[cfg(feature = "arabic")]
+        ((Script::Arabic, Language::Ara), Arc::new(ArabicSegmenter) as Arc<dyn Segmenter>),
+        // the other languages of the arabic script, the language is detected to pick the arabic segmenter.
+        #[cfg(feature = "arabic")]
+        ((Script::Arabic, Language::Other), Arc::new(LatinSegmenter) as Arc<dyn Segmenter>),
         // hebrew segmenter
         #[cfg(feature = "hebrew")]
-        ((Script::Hebrew, Language::Heb), Box::new(HebrewSegmenter) as Box<dyn Segmenter>),
+        ((Script::Hebrew, Language::Heb), Arc::new(HebrewSegmenter) as Arc<dyn Segmenter>),
         // japanese segmenter
         #[cfg(feature = "japanese")]
-        ((Script::Cj, Language::Jpn), Box::new(JapaneseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Language::Jpn), Arc::new(JapaneseSegmenter) as Arc<dyn Segmenter>),
         // korean segmenter
         #[cfg(feature = "korean")]
-        ((Script::Hangul, Language::Kor), Box::new(KoreanSegmenter) as Box<dyn Segmenter>),
+        ((Script::Hangul, Language::Kor), Arc::new(KoreanSegmenter) as Arc<dyn Segmenter>),
         // thai segmenter
         #[cfg(feature = "thai")]
-        ((Script::Thai, Language::Tha), Box::new(ThaiSegmenter) as Box<dyn Segmenter>),
+        ((Script::Thai, Language::Tha), Arc::new(ThaiSegmenter) as Arc<dyn Segmenter>),
+        // khmer segmenter
+        #[cfg(feature = "khmer")]
+        ((Script::Khmer, Language::Khm), Arc::new(KhmerSegmenter) as Arc<dyn Segmenter>),
+        // lao segmenter
+        #[cfg(feature = "lao")]
+        ((Script::Lao, Language::Other), Arc::new(LaoSegmenter) as Arc<dyn Segmenter>),
+        // myanmar segmenter
+        #[cfg(feature = "myanmar")]
+        ((Script::Myanmar, Language::Mya), Arc::new(MyanmarSegmenter) as Arc<dyn Segmenter>),
+        // tibetan segmenter
+        #[cfg(feature = "tibetan")]
+        ((Script::Tibetan, Language::Other), Arc::new(TibetanSegmenter) as Arc<dyn Segmenter>),
+        // vietnamese segmenter
+        #[cfg(feature = "vietnamese")]
+        ((Script::Latin, Language::Vie), Arc::new(VietnameseSegmenter) as Arc<dyn Segmenter>),
     ]
     .into_iter()
     .collect()
@@ -72,16 +151,24 @@ pub static SEGMENTERS: Lazy<HashMap<(Script, Language), Box<dyn Segmenter>>> = L
 pub static DEFAULT_SEGMENTER: Lazy<Box<dyn Segmenter>> = Lazy::new(|| Box::new(LatinSegmenter));
 
 /// Iterator over segmented [`Token`]s.
+///
+/// The subwords of a word, see [`Segmenter::subwords`], are emitted right after the word.
 pub struct SegmentedTokenIter<'o, 'al> {
     inner: SegmentedStrIter<'o, 'al>,
     char_index: usize,
     byte_index: usize,
+    /// subwords of the last emitted token.
+    pending: VecDeque<Token<'o>>,
 }
 
 impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
     type Item = Token<'o>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(subword) = self.pending.pop_front() {
+            return Some(subword);
+        }
+
         let lemma = self.inner.next()?;
         let char_start = self.char_index;
         let byte_start = self.byte_index;
@@ -89,8 +176,29 @@ impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
         self.char_index += lemma.chars().count();
         self.byte_index += lemma.len();
 
+        // protected terms are never split and always considered as words.
+        let kind = if self.inner.protected { TokenKind::Word } else { TokenKind::Unknown };
+
+        if let Some(segmenter) = self.inner.segmenter.as_deref().filter(|_| !self.inner.protected) {
+            for Range { start, end } in segmenter.subwords(lemma) {
+                let char_start = char_start + lemma[..start].chars().count();
+                self.pending.push_back(Token {
+                    lemma: Cow::Borrowed(&lemma[start..end]),
+                    script: self.inner.script,
+                    language: self.inner.language,
+                    char_start,
+                    char_end: char_start + lemma[start..end].chars().count(),
+                    byte_start: byte_start + start,
+                    byte_end: byte_start + end,
+                    is_subword: true,
+                    ..Default::default()
+                });
+            }
+        }
+
         Some(Token {
             lemma: Cow::Borrowed(lemma),
+            kind,
             script: self.inner.script,
             language: self.inner.language,
             char_start,
@@ -104,51 +212,181 @@ impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
 
 impl<'o, 'al> From<SegmentedStrIter<'o, 'al>> for SegmentedTokenIter<'o, 'al> {
     fn from(segmented_str_iter: SegmentedStrIter<'o, 'al>) -> Self {
-        Self { inner: segmented_str_iter, char_index: 0, byte_index: 0 }
+        Self { inner: segmented_str_iter, char_index: 0, byte_index: 0, pending: VecDeque::new() }
+    }
+}
+
+/// Options used to segment a text.
+#[derive(Clone, Copy)]
+pub struct SegmenterOption<'so> {
+    /// Languages that can be detected for each script.
+    pub allow_list: Option<&'so HashMap<Script, Vec<Language>>>,
+    /// [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`], see [`SEGMENTERS`].
+    pub segmenters: &'so SegmenterMap,
+    /// Characters that must not be split from the adjacent words, see [`SeparatorPolicy::word_char`].
+    pub separator_policy: Option<&'so SeparatorPolicy>,
+    /// Terms that must never be split, see [`ProtectedTerms`].
+    pub protected_terms: Option<&'so ProtectedTerms>,
+    /// Detect the [`Language`] of every segmented text,
+    /// otherwise it's only detected to choose between several [`Segmenter`]s of the same [`Script`].
+    pub detect_language: bool,
+}
+
+impl Default for SegmenterOption<'_> {
+    fn default() -> Self {
+        Self {
+            allow_list: None,
+            segmenters: &SHARED_SEGMENTERS,
+            separator_policy: None,
+            protected_terms: None,
+            detect_language: false,
+        }
+    }
+}
+
+/// Same as [`SegmenterOption`] but the settings can be shared instead of being borrowed,
+/// used by the [`Tokenizer`](crate::Tokenizer) to create iterators that don't borrow it.
+#[derive(Clone)]
+pub(crate) struct SharedSegmenterOption<'so> {
+    pub allow_list: Option<Shared<'so, HashMap<Script, Vec<Language>>>>,
+    pub segmenters: Shared<'so, SegmenterMap>,
+    pub separator_policy: Option<Shared<'so, SeparatorPolicy>>,
+    pub protected_terms: Option<Shared<'so, ProtectedTerms>>,
+    pub detect_language: bool,
+}
+
+impl<'so> From<SegmenterOption<'so>> for SharedSegmenterOption<'so> {
+    fn from(option: SegmenterOption<'so>) -> Self {
+        Self {
+            allow_list: option.allow_list.map(Shared::Borrowed),
+            segmenters: Shared::Borrowed(option.segmenters),
+            separator_policy: option.separator_policy.map(Shared::Borrowed),
+            protected_terms: option.protected_terms.map(Shared::Borrowed),
+            detect_language: option.detect_language,
+        }
     }
 }
 
 pub struct SegmentedStrIter<'o, 'al> {
-    inner: Box<dyn Iterator<Item = &'o str> + 'o>,
-    current: Box<dyn Iterator<Item = &'o str> + 'o>,
-    allow_list: Option<&'al HashMap<Script, Vec<Language>>>,
+    /// texts sharing the same script, protected terms are flagged and kept apart.
+    inner: Box<dyn Iterator<Item = (&'o str, bool)> + 'o>,
+    current: Peekable<Box<dyn Iterator<Item = &'o str> + 'o>>,
+    /// text currently segmented and the byte offset of the next lemma in it.
+    text: &'o str,
+    offset: usize,
+    option: SharedSegmenterOption<'al>,
     script: Script,
     language: Option<Language>,
+    protected: bool,
+    /// segmenter of the current text, `None` for protected terms.
+    segmenter: Option<Shared<'al, dyn Segmenter>>,
 }
 
 impl<'o, 'al> SegmentedStrIter<'o, 'al> {
     pub fn new(original: &'o str, allow_list: Option<&'al HashMap<Script, Vec<Language>>>) -> Self {
-        let mut current_script = Script::Other;
-        let inner = original.linear_group_by_key(move |c| {
-            let script = Script::from(c);
-            if script != Script::Other && script != current_script {
-                current_script = script
+        Self::with_option(original, SegmenterOption { allow_list, ..Default::default() })
+    }
+
+    pub fn with_option(original: &'o str, option: SegmenterOption<'al>) -> Self {
+        Self::with_shared_option(original, option.into())
+    }
+
+    pub(crate) fn with_shared_option(
+        original: &'o str,
+        option: SharedSegmenterOption<'al>,
+    ) -> Self {
+        // protected terms are searched upfront to be kept out of the script specialized segmentation.
+        let mut parts = Vec::new();
+        let mut last_end = 0;
+        let spans = option.protected_terms.as_deref().map(|terms| terms.find(original));
+        for span in spans.unwrap_or_default() {
+            if last_end < span.start {
+                parts.push((&original[last_end..span.start], false));
             }
-            current_script
+            last_end = span.end;
+            parts.push((&original[span], true));
+        }
+        if last_end < original.len() {
+            parts.push((&original[last_end..], false));
+        }
+
+        let inner = parts.into_iter().flat_map(|(text, protected)| {
+            let texts: Box<dyn Iterator<Item = &'o str> + 'o> = match protected {
+                true => Box::new(once(text)),
+                false => Box::new(group_by_script(text)),
+            };
+            texts.map(move |text| (text, protected))
         });
 
+        let current: Box<dyn Iterator<Item = &'o str> + 'o> = Box::new(None.into_iter());
         Self {
             inner: Box::new(inner),
-            current: Box::new(None.into_iter()),
-            allow_list,
+            current: current.peekable(),
+            text: "",
+            offset: 0,
+            option,
             script: Script::Other,
             language: None,
+            protected: false,
+            segmenter: None,
         }
     }
 }
 
+/// Splits the provided text in parts sharing the same [`Script`].
+fn group_by_script(text: &str) -> impl Iterator<Item = &str> {
+    let mut current_script = Script::Other;
+    text.linear_group_by_key(move |c| {
+        let script = Script::from(c);
+        if script != Script::Other && script != current_script {
+            current_script = script
+        }
+        current_script
+    })
+}
+
 impl<'o, 'al> Iterator for SegmentedStrIter<'o, 'al> {
     type Item = &'o str;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.current.next() {
-            Some(s) => Some(s),
+            Some(s) => {
+                let start = self.offset;
+                self.offset += s.len();
+                if let Some(policy) = self.option.separator_policy.as_deref() {
+                    // join the following lemmas as long as they are glued by word characters.
+                    while let Some(next) = self.current.peek() {
+                        if !join_word_chars(policy, &self.text[start..self.offset], next) {
+                            break;
+                        }
+                        self.offset += next.len();
+                        self.current.next();
+                    }
+                }
+
+                Some(&self.text[start..self.offset])
+            }
             None => {
-                let text = self.inner.next()?;
-                let mut detector = text.detect(self.allow_list);
-                self.current = segmenter(&mut detector).segment_str(text);
+                let (text, protected) = self.inner.next()?;
+                let mut detector = text.detect(self.option.allow_list.as_deref());
+                self.segmenter = match protected {
+                    true => None,
+                    false => Some(segmenter(&mut detector, &self.option.segmenters)),
+                };
+                let current: Box<dyn Iterator<Item = &'o str> + 'o> =
+                    match self.segmenter.as_deref() {
+                        Some(segmenter) => segmenter.segment_str(text),
+                        None => Box::new(once(text)),
+                    };
+                self.current = current.peekable();
+                self.protected = protected;
+                self.text = text;
+                self.offset = 0;
                 self.script = detector.script();
-                self.language = detector.language;
+                self.language = match self.option.detect_language {
+                    true => Some(detector.language()),
+                    false => detector.language,
+                };
 
                 self.next()
             }
@@ -156,31 +394,61 @@ impl<'o, 'al> Iterator for SegmentedStrIter<'o, 'al> {
     }
 }
 
-/// Try to Detect Language and Script and return the corresponding segmenter,
+/// Returns true if the lemmas `left` and `right` are glued by a character
+/// that the [`SeparatorPolicy`] marks as a word character.
+fn join_word_chars(policy: &SeparatorPolicy, left: &str, right: &str) -> bool {
+    let is_separator = |s: &str| s.chars().all(|c| policy.classify(c).is_some());
+    let glued = left.chars().next_back().is_some_and(|c| policy.is_word_char(c))
+        || right.chars().next().is_some_and(|c| policy.is_word_char(c));
+
+    glued && !is_separator(left) && !is_separator(right)
+}
+
+/// Try to Detect Language and Script and return the corresponding segmenter in the provided map,
 /// if no Language is detected or no segmenter corresponds to the Language
 /// the function try to get a segmenter corresponding to the script;
 /// if no Script is detected or no segmenter corresponds to the Script,
 /// the function try to get the default segmenter in the map;
 /// if no default segmenter exists in the map return the library DEFAULT_SEGMENTER.
-fn segmenter<'b>(detector: &mut StrDetection) -> &'b impl Segmenter {
+fn segmenter<'al>(
+    detector: &mut StrDetection,
+    segmenters: &Shared<'al, SegmenterMap>,
+) -> Shared<'al, dyn Segmenter> {
+    match segmenters {
+        Shared::Borrowed(segmenters) => match find_segmenter(detector, segmenters) {
+            Some(segmenter) => Shared::Borrowed(&**segmenter),
+            None => Shared::Borrowed(&**DEFAULT_SEGMENTER),
+        },
+        Shared::Arc(segmenters) => match find_segmenter(detector, segmenters) {
+            Some(segmenter) => Shared::Arc(segmenter.clone()),
+            None => Shared::Borrowed(&**DEFAULT_SEGMENTER),
+        },
+    }
+}
+
+/// Returns the segmenter of the provided map corresponding to the detected Script and Language,
+/// see [`segmenter`], `None` meaning that the DEFAULT_SEGMENTER must be used.
+fn find_segmenter<'b>(
+    detector: &mut StrDetection,
+    segmenters: &'b SegmenterMap,
+) -> Option<&'b Arc<dyn Segmenter>> {
     let detected_script = detector.script();
     let mut filtered_segmenters =
-        SEGMENTERS.iter().filter(|((script, _), _)| *script == detected_script);
+        segmenters.iter().filter(|((script, _), _)| *script == detected_script);
     match (filtered_segmenters.next(), filtered_segmenters.next()) {
         // no specialized segmenter found for this script,
         // choose the default one.
-        (None, None) => &*DEFAULT_SEGMENTER,
+        (None, None) => None,
         // Only one specialized segmenter found,
         // we don't need to detect the Language.
-        (Some((_, segmenter)), None) => segmenter,
+        (Some((_, segmenter)), None) => Some(segmenter),
         // several segmenters found,
         // we have to detect the language to get the good one.
         _ => {
             let detected_language = detector.language();
-            SEGMENTERS
+            segmenters
                 .get(&(detected_script, detected_language))
-                .or_else(|| SEGMENTERS.get(&(detected_script, Language::Other)))
-                .unwrap_or(&DEFAULT_SEGMENTER)
+                .or_else(|| segmenters.get(&(detected_script, Language::Other)))
         }
     }
 }
@@ -191,12 +459,35 @@ fn segmenter<'b>(detector: &mut StrDetection) -> &'b impl Segmenter {
 pub trait Segmenter: Sync + Send {
     /// Segments the provided text creating an Iterator over `&str`.
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o>;
+
+    /// Returns the byte ranges of the subwords of the provided segment,
+    /// like the parts of a compound word or the stem of a word carrying prefixes,
+    /// the subwords are emitted as subword [`Token`]s after the segment.
+    ///
+    /// By default, no segment has subwords.
+    fn subwords(&self, _segment: &str) -> Vec<Range<usize>> {
+        Vec::new()
+    }
 }
 
 impl Segmenter for Box<dyn Segmenter> {
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
         (**self).segment_str(s)
     }
+
+    fn subwords(&self, segment: &str) -> Vec<Range<usize>> {
+        (**self).subwords(segment)
+    }
+}
+
+impl Segmenter for Arc<dyn Segmenter> {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        (**self).segment_str(s)
+    }
+
+    fn subwords(&self, segment: &str) -> Vec<Range<usize>> {
+        (**self).subwords(segment)
+    }
 }
 
 /// Trait defining methods to segment a text.
@@ -242,6 +533,14 @@ pub trait Segment<'o> {
         self.segment_str_with_allowlist(allow_list).into()
     }
 
+    /// Segments the provided text creating an Iterator over Tokens using the provided [`SegmenterOption`].
+    fn segment_with_option<'so>(
+        &self,
+        option: SegmenterOption<'so>,
+    ) -> SegmentedTokenIter<'o, 'so> {
+        self.segment_str_with_option(option).into()
+    }
+
     /// Segments the provided text creating an Iterator over `&str`.
     ///
     /// # Example
@@ -285,15 +584,23 @@ pub trait Segment<'o> {
     fn segment_str_with_allowlist<'al>(
         &self,
         allow_list: Option<&'al HashMap<Script, Vec<Language>>>,
-    ) -> SegmentedStrIter<'o, 'al>;
+    ) -> SegmentedStrIter<'o, 'al> {
+        self.segment_str_with_option(SegmenterOption { allow_list, ..Default::default() })
+    }
+
+    /// Segments the provided text creating an Iterator over `&str` using the provided [`SegmenterOption`].
+    fn segment_str_with_option<'so>(
+        &self,
+        option: SegmenterOption<'so>,
+    ) -> SegmentedStrIter<'o, 'so>;
 }
 
 impl<'o> Segment<'o> for &'o str {
-    fn segment_str_with_allowlist<'al>(
+    fn segment_str_with_option<'so>(
         &self,
-        allow_list: Option<&'al HashMap<Script, Vec<Language>>>,
-    ) -> SegmentedStrIter<'o, 'al> {
-        SegmentedStrIter::new(self, allow_list)
+        option: SegmenterOption<'so>,
+    ) -> SegmentedStrIter<'o, 'so> {
+        SegmentedStrIter::with_option(self, option)
     }
 }
 
@@ -334,7 +641,7 @@ Check if the expected Script/Language corresponds to the detected Script/Languag
 Segmenter chosen by global segment() function, didn't segment the text as expected.
 
 help: The selected segmenter is probably the wrong one.
-Check if the tested segmenter is assigned to the good Script/Language in `SEGMENTERS` global in `charabia/src/segmenter/mod.rs`.
+Check if the tested segmenter is assigned to the good Script/Language in `SHARED_SEGMENTERS` global in `charabia/src/segmenter/mod.rs`.
 "#);
             }
 
@@ -0,0 +1,212 @@
+use fst::raw::Fst;
+use once_cell::sync::Lazy;
+use slice_group_by::StrGroupBy;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::utils::find_prefixes;
+use super::Segmenter;
+
+const ASAT: char = '\u{103A}';
+const VIRAMA: char = '\u{1039}';
+const DOT_BELOW: char = '\u{1037}';
+
+static WORDS_FST: Lazy<Fst<&[u8]>> = Lazy::new(|| {
+    Fst::new(&include_bytes!("../../dictionaries/fst/myanmar/words.fst")[..]).unwrap()
+});
+
+/// Myanmar specialized [`Segmenter`].
+///
+/// Burmese is written without spaces between words,
+/// this Segmenter breaks the text into syllables using the Myanmar orthography rules:
+/// a syllable starts with a consonant, an independent vowel, a digit or a punctuation mark,
+/// except if the consonant is a final marked by an asat or is stacked by a virama on the previous one.
+/// The syllables forming a word contained in a dictionary encoded as an FST are then merged,
+/// the longest word being chosen.
+///
+/// Dictionary source: the word list in `dictionaries/txt/myanmar`, about 300 common Burmese words
+/// (greetings, pronouns, family, places, food, time, numbers, common verbs and adjectives) compiled by hand,
+/// the syllables of the other words are kept apart.
+pub struct MyanmarSegmenter;
+
+impl Segmenter for MyanmarSegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        let lemmas = s.linear_group_by_key(is_myanmar).flat_map(|text| {
+            let lemmas: Box<dyn Iterator<Item = &'o str> + 'o> = match text.starts_with(is_myanmar)
+            {
+                true => Box::new(segment_words(text).into_iter()),
+                false => Box::new(text.split_word_bounds()),
+            };
+            lemmas
+        });
+
+        Box::new(lemmas)
+    }
+}
+
+/// Segments a Myanmar text in syllables and merges the syllables forming a dictionary word.
+fn segment_words(text: &str) -> Vec<&str> {
+    let bounds = syllable_bounds(text);
+
+    let mut lemmas = Vec::new();
+    let mut start = 0;
+    for (index, &end) in bounds.iter().enumerate() {
+        if end <= start {
+            continue;
+        }
+
+        // pick the longest word ending at a syllable bound after the current syllable.
+        let word_end = find_prefixes(&WORDS_FST, &text.as_bytes()[start..])
+            .into_iter()
+            .rev()
+            .map(|len| start + len)
+            .find(|word_end| *word_end > end && bounds[index..].binary_search(word_end).is_ok());
+
+        let end = word_end.unwrap_or(end);
+        lemmas.push(&text[start..end]);
+        start = end;
+    }
+
+    lemmas
+}
+
+/// Returns the end byte offsets of the syllables of a Myanmar text.
+fn syllable_bounds(text: &str) -> Vec<usize> {
+    let chars: Vec<_> = text.char_indices().collect();
+    let mut bounds = Vec::new();
+    for (i, &(offset, c)) in chars.iter().enumerate().skip(1) {
+        let previous = chars[i - 1].1;
+        // the dot below can be written before the asat of a final consonant.
+        let next = chars[i + 1..].iter().map(|(_, c)| *c).find(|c| *c != DOT_BELOW);
+
+        let is_final = matches!(next, Some(ASAT | VIRAMA));
+        let is_stacked = previous == VIRAMA;
+        let is_number = is_digit(previous) && is_digit(c);
+        if is_syllable_start(c) && !is_final && !is_stacked && !is_number {
+            bounds.push(offset);
+        }
+    }
+    bounds.push(text.len());
+
+    bounds
+}
+
+fn is_myanmar(c: char) -> bool {
+    matches!(c, '\u{1000}'..='\u{109F}' | '\u{A9E0}'..='\u{A9FF}' | '\u{AA60}'..='\u{AA7F}')
+}
+
+/// Returns true if the character can start a syllable:
+/// consonants, independent vowels, digits, punctuation marks and symbols.
+fn is_syllable_start(c: char) -> bool {
+    matches!(c,
+        '\u{1000}'..='\u{102A}'
+        | '\u{103F}'..='\u{104F}'
+        | '\u{1050}'..='\u{1055}'
+        | '\u{105A}'..='\u{105D}'
+        | '\u{1061}'
+        | '\u{1065}'..='\u{1066}'
+        | '\u{106E}'..='\u{1070}'
+        | '\u{1075}'..='\u{1081}'
+        | '\u{108E}'
+        | '\u{1090}'..='\u{1099}'
+        | '\u{A9E0}'..='\u{A9E4}'
+        | '\u{A9E7}'..='\u{A9EF}'
+        | '\u{A9F0}'..='\u{A9F9}'
+        | '\u{A9FA}'..='\u{A9FE}'
+        | '\u{AA60}'..='\u{AA7A}'
+    )
+}
+
+fn is_digit(c: char) -> bool {
+    matches!(c, '\u{1040}'..='\u{1049}' | '\u{1090}'..='\u{1099}' | '\u{A9F0}'..='\u{A9F9}')
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+    use crate::SeparatorKind;
+
+    const TEXT: &str = "မင်္ဂလာပါ။ ကျွန်တော်သည် မြန်မာနိုင်ငံ ရန်ကုန်မြို့တွင် နေထိုင်ပါသည်။ ၂၀၂၃ ခုနှစ်";
+
+    const SEGMENTED: &[&str] = &[
+        "မင်္ဂလာပါ",
+        "။",
+        " ",
+        "ကျွန်တော်",
+        "သည်",
+        " ",
+        "မြန်မာ",
+        "နိုင်ငံ",
+        " ",
+        "ရန်ကုန်",
+        "မြို့",
+        "တွင်",
+        " ",
+        "နေထိုင်",
+        "ပါ",
+        "သည်",
+        "။",
+        " ",
+        "၂၀၂၃",
+        " ",
+        "ခုနှစ်",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "မင်္ဂလာပါ",
+        "။",
+        " ",
+        "ကျွန်တော်",
+        "သည်",
+        " ",
+        "မြန်မာ",
+        "နိုင်ငံ",
+        " ",
+        "ရန်ကုန်",
+        "မြို့",
+        "တွင်",
+        " ",
+        "နေထိုင်",
+        "ပါ",
+        "သည်",
+        "။",
+        " ",
+        "၂၀၂၃",
+        " ",
+        "ခုနှစ်",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(MyanmarSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Myanmar, Language::Mya);
+
+    #[test]
+    fn syllables() {
+        // "ဒူးရင်းသီး" (durian) is not in the dictionary and is split in syllables,
+        // the kinzi and the stacked consonant stay in their syllable.
+        let segmented: Vec<_> = MyanmarSegmenter.segment_str("ဒူးရင်းသီး သင်္ချိုင်း").collect();
+        assert_eq!(segmented, ["ဒူး", "ရင်း", "သီး", " ", "သင်္ချိုင်း"]);
+
+        // the dictionary merges the syllables of a word.
+        let segmented: Vec<_> = MyanmarSegmenter.segment_str("မန္တလေးတက္ကသိုလ်").collect();
+        assert_eq!(segmented, ["မန္တလေး", "တက္ကသိုလ်"]);
+    }
+
+    #[test]
+    fn shipped_dictionary() {
+        let txt = include_str!("../../dictionaries/txt/myanmar/words.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        words.dedup();
+        let set = fst::Set::from_iter(words).unwrap();
+        assert_eq!(
+            set.as_fst().as_bytes(),
+            include_bytes!("../../dictionaries/fst/myanmar/words.fst")
+        );
+    }
+
+    #[test]
+    fn section_mark_is_a_hard_separator() {
+        let token = "ပါ။".tokenize().nth(1).unwrap();
+        assert_eq!(token.separator_kind(), Some(SeparatorKind::Hard));
+    }
+}
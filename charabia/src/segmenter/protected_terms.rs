@@ -0,0 +1,196 @@
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use fst::raw::Fst;
+use fst::Set;
+
+/// Dictionary of terms that must never be split by the segmenters.
+///
+/// Protected terms are searched in the text before the Script specialized segmentation,
+/// each occurrence is then segmented as a single lemma classified as a [`TokenKind::Word`].
+/// An occurrence is only protected if it isn't glued to an other alphanumeric character,
+/// for instance "C++" is found in "I like C++!" but not in "ABC++".
+///
+/// [`TokenKind::Word`]: crate::TokenKind::Word
+///
+/// # Example
+///
+/// ```
+/// use charabia::segmenter::ProtectedTerms;
+/// use charabia::TokenizerBuilder;
+///
+/// let protected_terms = ProtectedTerms::from_terms(["C++", "Wi-Fi", "AT&T"], true).unwrap();
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.protected_terms(protected_terms);
+/// let tokenizer = builder.build();
+///
+/// let mut tokens = tokenizer.tokenize("c++ over wi-fi");
+/// let token = tokens.next().unwrap();
+/// assert_eq!(token.lemma(), "c++");
+/// assert!(token.is_word());
+/// assert_eq!(tokens.nth(3).unwrap().lemma(), "wi-fi");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProtectedTerms {
+    terms: Set<Vec<u8>>,
+    case_insensitive: bool,
+}
+
+impl ProtectedTerms {
+    /// Create a `ProtectedTerms` dictionary from a list of terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - the terms to protect.
+    /// * `case_insensitive` - a `bool` that indicates whether the terms should be found regardless of their case.
+    pub fn from_terms<I, S>(terms: I, case_insensitive: bool) -> Result<Self, fst::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let terms: BTreeSet<_> = terms
+            .into_iter()
+            .map(|term| match case_insensitive {
+                true => term.as_ref().to_lowercase(),
+                false => term.as_ref().to_string(),
+            })
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        Ok(Self { terms: Set::from_iter(terms)?, case_insensitive })
+    }
+
+    /// Create a `ProtectedTerms` dictionary from an already built `Set`.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - the `Set` of terms to protect, terms must be lowercased if `case_insensitive` is `true`.
+    /// * `case_insensitive` - a `bool` that indicates whether the terms should be found regardless of their case.
+    pub fn from_set(terms: Set<Vec<u8>>, case_insensitive: bool) -> Self {
+        Self { terms, case_insensitive }
+    }
+
+    /// Returns true if the provided text is a protected term.
+    pub fn contains(&self, text: &str) -> bool {
+        match self.case_insensitive {
+            true => self.terms.contains(text.to_lowercase()),
+            false => self.terms.contains(text),
+        }
+    }
+
+    /// Returns the byte ranges of the protected terms found in the provided text.
+    pub(crate) fn find(&self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<_> = text.char_indices().collect();
+        // the text is lowercased once, each char is given with the end of its lowercased form.
+        let lowercased: String;
+        let (normalized, ends): (&str, Vec<_>) = match self.case_insensitive {
+            true => {
+                let mut s = String::with_capacity(text.len());
+                let ends = chars
+                    .iter()
+                    .map(|(_, c)| {
+                        s.extend(c.to_lowercase());
+                        s.len()
+                    })
+                    .collect();
+                lowercased = s;
+                (&lowercased, ends)
+            }
+            false => (text, chars.iter().map(|(offset, c)| offset + c.len_utf8()).collect()),
+        };
+
+        let mut found = Vec::new();
+        let mut index = 0;
+        while let Some(&(start, c)) = chars.get(index) {
+            // a term can't start in the middle of a word.
+            let glued_before = index
+                .checked_sub(1)
+                .is_some_and(|previous| chars[previous].1.is_alphanumeric() && c.is_alphanumeric());
+            match (!glued_before).then(|| self.longest_term(&chars, normalized, &ends, index)) {
+                Some(Some(last)) => {
+                    let (offset, c) = chars[last];
+                    found.push(start..offset + c.len_utf8());
+                    index = last + 1;
+                }
+                _ => index += 1,
+            }
+        }
+
+        found
+    }
+
+    /// Returns the index of the last char of the longest protected term starting at the char `first`
+    /// that isn't followed by an alphanumeric character.
+    fn longest_term(
+        &self,
+        chars: &[(usize, char)],
+        normalized: &str,
+        ends: &[usize],
+        first: usize,
+    ) -> Option<usize> {
+        let fst: &Fst<Vec<u8>> = self.terms.as_fst();
+        let mut node = fst.root();
+        let mut longest = None;
+        let mut byte_start = first.checked_sub(1).map_or(0, |previous| ends[previous]);
+
+        for (index, &(_, c)) in chars.iter().enumerate().skip(first) {
+            for &b in &normalized.as_bytes()[byte_start..ends[index]] {
+                match node.find_input(b) {
+                    Some(transition) => node = fst.node(node.transition(transition).addr),
+                    None => return longest,
+                }
+            }
+            byte_start = ends[index];
+
+            if node.is_final() {
+                // a term can't end in the middle of a word.
+                let glued_after = chars
+                    .get(index + 1)
+                    .is_some_and(|(_, next)| next.is_alphanumeric() && c.is_alphanumeric());
+                if !glued_after {
+                    longest = Some(index);
+                }
+            }
+        }
+
+        longest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn find_terms() {
+        let terms = ProtectedTerms::from_terms(["C++", "C", "Wi-Fi"], false).unwrap();
+
+        let text = "C++, C and Wi-Fi";
+        let found: Vec<_> = terms.find(text).into_iter().map(|span| &text[span]).collect();
+        assert_eq!(found, ["C++", "C", "Wi-Fi"]);
+
+        // terms glued to other words are not found.
+        assert!(terms.find("ABC++ Cd Wi-Fix wi-fi").is_empty());
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let terms = ProtectedTerms::from_terms(["Wi-Fi"], true).unwrap();
+        assert!(terms.contains("WI-FI"));
+        assert_eq!(terms.find("a WI-fi"), vec![2..7]);
+
+        let terms = ProtectedTerms::from_terms(["Wi-Fi"], false).unwrap();
+        assert!(!terms.contains("WI-FI"));
+        assert!(terms.find("a WI-fi").is_empty());
+    }
+
+    #[test]
+    fn lowercase_changing_length() {
+        // "İ" is lowercased into "i̇", which is longer.
+        let terms = ProtectedTerms::from_terms(["İstanbul", "K8s"], true).unwrap();
+        let text = "İSTANBUL and k8S";
+        let found: Vec<_> = terms.find(text).into_iter().map(|span| &text[span]).collect();
+        assert_eq!(found, ["İSTANBUL", "k8S"]);
+    }
+}
@@ -7,7 +7,8 @@ use crate::segmenter::Segmenter;
 
 /// Thai specialized [`Segmenter`].
 ///
-/// This Segmenter uses a dictionary encoded as an FST to segment the provided text.
+/// This Segmenter uses a dictionary encoded as an FST to segment the provided text,
+/// the text missing from the dictionary is split into grapheme clusters.
 /// Dictionary source: PyThaiNLP project on https://github.com/PyThaiNLP/nlpo3
 pub struct ThaiSegmenter;
 
@@ -70,4 +71,15 @@ mod test {
     ];
     // Macro that run several tests on the Segmenter.
     test_segmenter!(ThaiSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Thai, Language::Tha);
+
+    #[test]
+    fn out_of_vocabulary() {
+        let segmented: Vec<_> = ThaiSegmenter.segment_str("เฟซบุ๊กไทย").collect();
+        assert_eq!(segmented, ["เฟซบุ๊ก", "ไทย"]);
+
+        // a text missing from the dictionary is split in clusters keeping the signs with their consonant,
+        // and the leading vowels with the consonant following them.
+        let segmented: Vec<_> = ThaiSegmenter.segment_str("ภาษาไทยฮุ้เฮฮุ้").collect();
+        assert_eq!(segmented, ["ภาษาไทย", "ฮุ้", "เฮ", "ฮุ้"]);
+    }
 }
@@ -0,0 +1,148 @@
+use slice_group_by::StrGroupBy;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::Segmenter;
+
+/// Tibetan specialized [`Segmenter`].
+///
+/// Tibetan syllables are delimited by a tsheg (`་`) and sentences by a shad (`།`):
+/// this Segmenter splits the provided text on these marks, each run of marks being kept as a separator,
+/// the syllables are then segmented using [`UnicodeSegmentation`] to split the other punctuation marks.
+pub struct TibetanSegmenter;
+
+impl Segmenter for TibetanSegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        let lemmas = s.linear_group_by_key(Delimiter::from).flat_map(|text| {
+            let lemmas: Box<dyn Iterator<Item = &'o str> + 'o> =
+                match text.starts_with(|c| Delimiter::from(c) == Delimiter::None) {
+                    true => Box::new(text.split_word_bounds()),
+                    false => Box::new(std::iter::once(text)),
+                };
+            lemmas
+        });
+
+        Box::new(lemmas)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Delimiter {
+    Tsheg,
+    Shad,
+    None,
+}
+
+impl From<char> for Delimiter {
+    fn from(c: char) -> Self {
+        match c {
+            '\u{0F0B}' | '\u{0F0C}' | '\u{0F14}' => Delimiter::Tsheg,
+            '\u{0F08}' | '\u{0F0D}'..='\u{0F12}' => Delimiter::Shad,
+            _ => Delimiter::None,
+        }
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+    use crate::SeparatorKind;
+
+    const TEXT: &str = "བོད་ཀྱི་སྐད་ཡིག་ནི་ཧ་ཅང་གལ་ཆེན་པོ་རེད། ང་བོད་པ་ཡིན།(༡༩༥༩)";
+
+    const SEGMENTED: &[&str] = &[
+        "བོད",
+        "་",
+        "ཀྱི",
+        "་",
+        "སྐད",
+        "་",
+        "ཡིག",
+        "་",
+        "ནི",
+        "་",
+        "ཧ",
+        "་",
+        "ཅང",
+        "་",
+        "གལ",
+        "་",
+        "ཆེན",
+        "་",
+        "པོ",
+        "་",
+        "རེད",
+        "།",
+        " ",
+        "ང",
+        "་",
+        "བོད",
+        "་",
+        "པ",
+        "་",
+        "ཡིན",
+        "།",
+        "(",
+        "༡༩༥༩",
+        ")",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "བོད",
+        "་",
+        "ཀྱི",
+        "་",
+        "སྐད",
+        "་",
+        "ཡིག",
+        "་",
+        "ནི",
+        "་",
+        "ཧ",
+        "་",
+        "ཅང",
+        "་",
+        "གལ",
+        "་",
+        "ཆེན",
+        "་",
+        "པོ",
+        "་",
+        "རེད",
+        "།",
+        " ",
+        "ང",
+        "་",
+        "བོད",
+        "་",
+        "པ",
+        "་",
+        "ཡིན",
+        "།",
+        "(",
+        "༡༩༥༩",
+        ")",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(TibetanSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Tibetan, Language::Other);
+
+    #[test]
+    fn separator_kinds() {
+        let kinds: Vec<_> = "བོད་ཡིག། ང"
+            .tokenize()
+            .map(|token| (token.lemma().to_string(), token.separator_kind()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("བོད".to_string(), None),
+                ("་".to_string(), Some(SeparatorKind::Soft)),
+                ("ཡིག".to_string(), None),
+                ("།".to_string(), Some(SeparatorKind::Hard)),
+                (" ".to_string(), Some(SeparatorKind::Soft)),
+                ("ང".to_string(), None),
+            ]
+        );
+    }
+}
@@ -1,4 +1,11 @@
 use fst::raw::{Fst, Output};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Khmer sign writing the following consonant as a subscript of the previous one.
+const KHMER_COENG: char = '\u{17D2}';
+/// Thai and Lao vowels written before the consonant they follow in pronunciation.
+const LEADING_VOWELS: [std::ops::RangeInclusive<char>; 2] =
+    ['\u{0E40}'..='\u{0E44}', '\u{0EC0}'..='\u{0EC4}'];
 
 /// Final-state-transducer (FST) Segmenter
 pub(crate) struct FstSegmenter<'fst> {
@@ -25,11 +32,8 @@ impl<'fst> FstSegmenter<'fst> {
 
             let length = match find_longest_prefix(self.words_fst, to_segment.as_bytes()) {
                 Some((_, length)) => length,
-                None => {
-                    // if no sequence matches, we return the next character as a lemma.
-                    let first = to_segment.chars().next().unwrap();
-                    first.len_utf8()
-                }
+                // if no sequence matches, we return the next cluster as a lemma.
+                None => first_cluster_len(to_segment),
             };
 
             let (left, right) = to_segment.split_at(length);
@@ -41,6 +45,24 @@ impl<'fst> FstSegmenter<'fst> {
     }
 }
 
+/// Returns the byte length of the first grapheme cluster of the text,
+/// so the vowel signs and the tone marks are never separated from their consonant,
+/// a cluster ending with a Khmer coeng or made of a Thai or Lao leading vowel
+/// is joined with the consonant following it.
+fn first_cluster_len(text: &str) -> usize {
+    let mut len = 0;
+    for grapheme in text.graphemes(true) {
+        len += grapheme.len();
+        let leading_vowel = grapheme.chars().next().is_some_and(|c| {
+            grapheme.len() == c.len_utf8() && LEADING_VOWELS.iter().any(|r| r.contains(&c))
+        });
+        if !grapheme.ends_with(KHMER_COENG) && !leading_vowel {
+            break;
+        }
+    }
+    len
+}
+
 /// Thanks to @llogiq for this function
 /// https://github.com/BurntSushi/fst/pull/104/files
 ///
@@ -70,3 +92,23 @@ fn find_longest_prefix(fst: &Fst<&[u8]>, value: &[u8]) -> Option<(u64, usize)> {
     }
     last_match
 }
+
+/// find the lengths of all the keys that are prefixes of the given value,
+/// from the shortest to the longest.
+#[cfg(any(feature = "decompounding", feature = "myanmar", feature = "vietnamese"))]
+pub(crate) fn find_prefixes(fst: &Fst<&[u8]>, value: &[u8]) -> Vec<usize> {
+    let mut node = fst.root();
+    let mut lengths = Vec::new();
+    for (i, &b) in value.iter().enumerate() {
+        match node.find_input(b) {
+            Some(trans_index) => {
+                node = fst.node(node.transition(trans_index).addr);
+                if node.is_final() {
+                    lengths.push(i + 1);
+                }
+            }
+            None => break,
+        }
+    }
+    lengths
+}
@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+
+use fst::raw::Fst;
+use once_cell::sync::Lazy;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::utils::find_prefixes;
+use super::Segmenter;
+
+static WORDS_FST: Lazy<Fst<&[u8]>> = Lazy::new(|| {
+    Fst::new(&include_bytes!("../../dictionaries/fst/vietnamese/words.fst")[..]).unwrap()
+});
+
+/// Vietnamese specialized [`Segmenter`].
+///
+/// Vietnamese separates syllables by spaces, not words:
+/// this Segmenter segments the provided text using [`UnicodeSegmentation`]
+/// and groups the syllables forming a word, like "Hà Nội", in a single lemma.
+/// Syllables are grouped using a dictionary of lowercased words, separated by a single space, encoded as an FST,
+/// the longest word is chosen and the spaces between words are kept as separators.
+///
+/// Dictionary source: the word list in `dictionaries/txt/vietnamese`.
+pub struct VietnameseSegmenter;
+
+impl Segmenter for VietnameseSegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        // the dictionary is lowercased, matches are mapped back to the text only if the offsets are preserved.
+        let lowercased = s.to_lowercase();
+        let haystack = if lowercased.len() == s.len() { Cow::Owned(lowercased) } else { s.into() };
+
+        let bounds: Vec<_> =
+            s.split_word_bound_indices().map(|(start, segment)| start + segment.len()).collect();
+
+        let mut lemmas = Vec::new();
+        let mut start = 0;
+        for (index, &end) in bounds.iter().enumerate() {
+            if end <= start {
+                continue;
+            }
+
+            // pick the longest word ending at a segment bound after the current syllable.
+            let word_end = find_prefixes(&WORDS_FST, haystack[start..].as_bytes())
+                .into_iter()
+                .rev()
+                .map(|len| start + len)
+                .find(|word_end| {
+                    *word_end > end && bounds[index..].binary_search(word_end).is_ok()
+                });
+
+            let end = word_end.unwrap_or(end);
+            lemmas.push(&s[start..end]);
+            start = end;
+        }
+
+        Box::new(lemmas.into_iter())
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use fst::Set;
+
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "Tôi là sinh viên đại học ở Hà Nội, tôi thích máy tính và cà phê.";
+
+    const SEGMENTED: &[&str] = &[
+        "Tôi",
+        " ",
+        "là",
+        " ",
+        "sinh viên",
+        " ",
+        "đại học",
+        " ",
+        "ở",
+        " ",
+        "Hà Nội",
+        ",",
+        " ",
+        "tôi",
+        " ",
+        "thích",
+        " ",
+        "máy tính",
+        " ",
+        "và",
+        " ",
+        "cà phê",
+        ".",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "toi",
+        " ",
+        "la",
+        " ",
+        "sinh vien",
+        " ",
+        "đai hoc",
+        " ",
+        "o",
+        " ",
+        "ha noi",
+        ",",
+        " ",
+        "toi",
+        " ",
+        "thich",
+        " ",
+        "may tinh",
+        " ",
+        "va",
+        " ",
+        "ca phe",
+        ".",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(VietnameseSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Latin, Language::Vie);
+
+    #[test]
+    fn longest_word() {
+        let segmented: Vec<_> =
+            VietnameseSegmenter.segment_str("Thành phố Hồ Chí Minh, thành phố lớn").collect();
+        assert_eq!(segmented, ["Thành phố Hồ Chí Minh", ",", " ", "thành phố", " ", "lớn"]);
+    }
+
+    #[test]
+    fn common_words() {
+        let segmented: Vec<_> = VietnameseSegmenter
+            .segment_str("Hôm nay thời tiết đẹp, chúng tôi đi du lịch ở Đà Nẵng")
+            .filter(|lemma| lemma.chars().any(char::is_alphanumeric))
+            .collect();
+        assert_eq!(
+            segmented,
+            ["Hôm nay", "thời tiết", "đẹp", "chúng tôi", "đi", "du lịch", "ở", "Đà Nẵng"]
+        );
+    }
+
+    #[test]
+    fn shipped_dictionary() {
+        let txt = include_str!("../../dictionaries/txt/vietnamese/words.txt");
+        let mut words: Vec<_> = txt.lines().collect();
+        words.sort_unstable();
+        let set = Set::from_iter(words).unwrap();
+        assert_eq!(
+            set.as_fst().as_bytes(),
+            include_bytes!("../../dictionaries/fst/vietnamese/words.fst")
+        );
+    }
+}
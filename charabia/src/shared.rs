@@ -0,0 +1,31 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A setting either borrowed or shared between several [`Tokenizer`](crate::Tokenizer)s.
+///
+/// The iterators created by a `Tokenizer` hold their own handle on its settings,
+/// this way they don't borrow the `Tokenizer` itself.
+pub(crate) enum Shared<'a, T: ?Sized> {
+    Borrowed(&'a T),
+    Arc(Arc<T>),
+}
+
+impl<T: ?Sized> Deref for Shared<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Shared::Borrowed(inner) => inner,
+            Shared::Arc(inner) => inner,
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Shared<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Shared::Borrowed(inner) => Shared::Borrowed(inner),
+            Shared::Arc(inner) => Shared::Arc(inner.clone()),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::VecDeque;
+use std::io::{self, Read};
+use std::mem;
+
+use crate::detection::Script;
+use crate::{Token, Tokenizer};
+
+/// Number of bytes requested to the reader at once.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+/// Number of buffered bytes after which the text is tokenized even if no change of script was found.
+const MAX_BUFFER_SIZE: usize = 1024 * 1024;
+/// Minimum number of bytes carried over when a text without spaces is cut,
+/// so the words cut by the end of the buffer are segmented again with the following text.
+const SEGMENT_CARRY_SIZE: usize = 1024;
+
+/// Iterator over owned [`Token`]s read from an [`io::Read`], see [`Tokenizer::tokenize_reader`].
+pub struct StreamingTokenIter<'t, 'al, 'sw, R, A> {
+    tokenizer: &'t Tokenizer<'al, 'sw, A>,
+    reader: R,
+    /// decoded text waiting for a change of script to be tokenized.
+    buffer: String,
+    /// bytes of a character split by the last read.
+    incomplete: Vec<u8>,
+    tokens: VecDeque<Token<'static>>,
+    /// number of bytes and chars tokenized so far.
+    byte_offset: usize,
+    char_offset: usize,
+    eof: bool,
+}
+
+impl<'al, 'sw, A: AsRef<[u8]>> Tokenizer<'al, 'sw, A> {
+    /// Creates an Iterator over owned [`Token`]s read from the provided reader.
+    ///
+    /// The text is buffered until a change of [`Script`], so a text sharing the same script
+    /// is segmented and its language detected as a whole, like [`Tokenizer::tokenize`] does.
+    /// The offsets of the tokens are global to the whole stream.
+    ///
+    /// Note that a text without any change of script is tokenized once it reaches 1MiB,
+    /// cut at the beginning of its last word, or between two segments for the scripts without spaces,
+    /// the language is then detected on each part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let reader = "The quick fox jumps over the lazy dog.".as_bytes();
+    /// let tokenizer = TokenizerBuilder::default().build();
+    ///
+    /// let tokens: Vec<_> = tokenizer.tokenize_reader(reader).collect::<Result<_, _>>().unwrap();
+    /// let token = tokens.iter().find(|t| t.lemma() == "lazy").unwrap();
+    /// assert_eq!((token.byte_start, token.byte_end), (29, 33));
+    /// ```
+    pub fn tokenize_reader<R: Read>(&self, reader: R) -> StreamingTokenIter<'_, 'al, 'sw, R, A> {
+        StreamingTokenIter {
+            tokenizer: self,
+            reader,
+            buffer: String::new(),
+            incomplete: Vec::new(),
+            tokens: VecDeque::new(),
+            byte_offset: 0,
+            char_offset: 0,
+            eof: false,
+        }
+    }
+}
+
+impl<R: Read, A: AsRef<[u8]>> StreamingTokenIter<'_, '_, '_, R, A> {
+    /// Reads the next chunk of the reader and appends it to the buffer.
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let mut bytes = mem::take(&mut self.incomplete);
+        let start = bytes.len();
+        bytes.resize(start + READ_CHUNK_SIZE, 0);
+
+        let read = loop {
+            match self.reader.read(&mut bytes[start..]) {
+                Ok(read) => break read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    bytes.truncate(start);
+                    self.incomplete = bytes;
+                    return Err(e);
+                }
+            }
+        };
+        bytes.truncate(start + read);
+
+        if read == 0 {
+            self.eof = true;
+            if !bytes.is_empty() {
+                return Err(invalid_utf8());
+            }
+        }
+
+        match std::str::from_utf8(&bytes) {
+            Ok(text) => self.buffer.push_str(text),
+            // the chunk ends in the middle of a character, keep its bytes for the next read.
+            Err(e) if e.error_len().is_none() => {
+                let (valid, incomplete) = bytes.split_at(e.valid_up_to());
+                self.buffer.push_str(std::str::from_utf8(valid).map_err(|_| invalid_utf8())?);
+                self.incomplete = incomplete.to_vec();
+            }
+            Err(_) => return Err(invalid_utf8()),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the byte index of the beginning of the last segment of the buffer
+    /// that is followed by at least `SEGMENT_CARRY_SIZE` bytes,
+    /// used to cut the text of the scripts written without spaces, like Chinese or Thai.
+    fn segment_boundary(&self) -> Option<usize> {
+        let mut boundary = None;
+        let mut start = 0;
+        for segment in self.tokenizer.segment_str(&self.buffer) {
+            if start + SEGMENT_CARRY_SIZE > self.buffer.len() {
+                break;
+            }
+            if start > 0 {
+                boundary = Some(start);
+            }
+            start += segment.len();
+        }
+
+        boundary
+    }
+
+    /// Tokenizes the first `len` bytes of the buffer.
+    fn tokenize_buffer(&mut self, len: usize) {
+        let rest = self.buffer.split_off(len);
+        let text = mem::replace(&mut self.buffer, rest);
+
+        let (byte_offset, char_offset) = (self.byte_offset, self.char_offset);
+        self.tokens.extend(self.tokenizer.tokenize(&text).map(|token| {
+            let mut token = token.into_owned();
+            token.byte_start += byte_offset;
+            token.byte_end += byte_offset;
+            token.char_start += char_offset;
+            token.char_end += char_offset;
+            token
+        }));
+
+        self.byte_offset += text.len();
+        self.char_offset += text.chars().count();
+    }
+}
+
+impl<R: Read, A: AsRef<[u8]>> Iterator for StreamingTokenIter<'_, '_, '_, R, A> {
+    type Item = io::Result<Token<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.tokens.pop_front() {
+                return Some(Ok(token));
+            }
+
+            if self.eof {
+                if self.buffer.is_empty() {
+                    return None;
+                }
+                self.tokenize_buffer(self.buffer.len());
+                continue;
+            }
+
+            if let Err(e) = self.read_chunk() {
+                return Some(Err(e));
+            }
+
+            if self.eof {
+                continue;
+            }
+
+            match script_boundary(&self.buffer) {
+                Some(boundary) => self.tokenize_buffer(boundary),
+                None if self.buffer.len() >= MAX_BUFFER_SIZE => {
+                    let boundary = word_boundary(&self.buffer)
+                        .or_else(|| self.segment_boundary())
+                        .unwrap_or(self.buffer.len());
+                    self.tokenize_buffer(boundary)
+                }
+                None => (),
+            }
+        }
+    }
+}
+
+/// Returns the byte index of the last change of [`Script`] of the text,
+/// the characters without script belonging to the script preceding them like in the segmenter.
+fn script_boundary(text: &str) -> Option<usize> {
+    let mut boundary = None;
+    let mut current_script = Script::Other;
+    for (index, c) in text.char_indices() {
+        let script = Script::from(c);
+        if script != Script::Other && script != current_script {
+            if current_script != Script::Other {
+                boundary = Some(index);
+            }
+            current_script = script;
+        }
+    }
+
+    boundary
+}
+
+/// Returns the byte index of the beginning of the last word following a whitespace.
+fn word_boundary(text: &str) -> Option<usize> {
+    let mut boundary = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, previous)) = chars.next() {
+        let Some(&(index, c)) = chars.peek() else { break };
+        if previous.is_whitespace() && !c.is_whitespace() {
+            boundary = Some(index);
+        }
+    }
+
+    boundary
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    /// Reader returning a few bytes at a time to split the text at any byte.
+    struct SlowReader<'a>(&'a [u8], usize);
+
+    impl Read for SlowReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.1.min(self.0.len()).min(buf.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn global_offsets() {
+        let text = "Héllo wörld, the quick brown fox.\nΚαλημέρα κόσμε! Ünïcödé everywhere...";
+        let expected: Vec<_> = text.tokenize().collect();
+
+        let tokenizer = TokenizerBuilder::default().build();
+        for read_size in [1, 2, 3, 5, 7, 64] {
+            let tokens: Vec<_> = tokenizer
+                .tokenize_reader(SlowReader(text.as_bytes(), read_size))
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(tokens, expected, "read size: {read_size}");
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(script_boundary("hello world"), None);
+        assert_eq!(script_boundary("helloκόσμε"), Some(5));
+        assert_eq!(script_boundary("hello, κόσμε"), Some(7));
+        assert_eq!(script_boundary("  hello κόσμε world"), Some(19));
+
+        assert_eq!(word_boundary("hello world"), Some(6));
+        assert_eq!(word_boundary("hello world  "), Some(6));
+        assert_eq!(word_boundary("hello"), None);
+    }
+
+    #[test]
+    fn text_without_spaces() {
+        // a Thai text bigger than the buffer is cut between two words.
+        let text = "ภาษาไทยง่ายนิดเดียว".repeat(MAX_BUFFER_SIZE / 50);
+        assert!(text.len() > MAX_BUFFER_SIZE);
+        let expected: Vec<_> =
+            text.as_str().tokenize().map(|t| (t.lemma().to_string(), t.byte_start)).collect();
+
+        let tokenizer = TokenizerBuilder::default().build();
+        let tokens: Vec<_> = tokenizer
+            .tokenize_reader(text.as_bytes())
+            .map(|t| t.map(|t| (t.lemma().to_string(), t.byte_start)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert!(tokens == expected);
+    }
+
+    #[test]
+    fn invalid_stream() {
+        let tokenizer = TokenizerBuilder::default().build();
+        let result: Result<Vec<_>, _> =
+            tokenizer.tokenize_reader(&b"hello \xFF world"[..]).collect();
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // a truncated character at the end of the stream.
+        let result: Result<Vec<_>, _> = tokenizer.tokenize_reader(&b"hello \xC3"[..]).collect();
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
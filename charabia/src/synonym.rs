@@ -0,0 +1,361 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
+use crate::normalizer::NormalizedTokenIter;
+use crate::{SeparatorKind, Token, TokenKind, Tokenize, Tokenizer};
+
+/// Map of synonyms used to expand the tokenized text.
+///
+/// Sources and synonyms are tokenized before being stored, with the default settings using [`Synonyms::insert`]
+/// or with the [`Tokenizer`] of the text using [`Synonyms::insert_with`],
+/// a source is then matched against the normalized lemmas of the consecutive words of the text.
+/// A source can't be matched across a hard separator and the subwords are ignored.
+///
+/// The words of a multi-word synonym are emitted with consecutive [`Token::synonym_index`],
+/// so each synonym gets its own position sequence, see [`PositionedTokenIter`](crate::PositionedTokenIter).
+///
+/// # Example
+///
+/// ```
+/// use charabia::synonym::Synonyms;
+/// use charabia::Tokenize;
+///
+/// let mut synonyms = Synonyms::new();
+/// synonyms.insert("NYC", "New York").insert("TV", "television");
+///
+/// let tokens: Vec<_> = "nyc".tokenize().synonyms(&synonyms).collect();
+/// let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+/// assert_eq!(lemmas, ["nyc", "new", "york"]);
+///
+/// // synonyms have the same offsets as their source.
+/// assert!(tokens[1].is_synonym());
+/// assert_eq!((tokens[1].byte_start, tokens[1].byte_end), (0, 3));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Synonyms {
+    synonyms: HashMap<Vec<String>, Vec<Vec<String>>>,
+    /// number of words of the longest source.
+    max_source_len: usize,
+}
+
+impl Synonyms {
+    /// Create an empty synonym map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a synonym to the provided source, both can be composed of several words.
+    ///
+    /// Synonyms are not bidirectional, `synonym` is not expanded into `source`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - the text to expand.
+    /// * `synonym` - the text to emit along with the `source`.
+    pub fn insert(&mut self, source: &str, synonym: &str) -> &mut Self {
+        self.insert_words(words(source.tokenize()), words(synonym.tokenize()))
+    }
+
+    /// Same as [`insert`](Self::insert) but the source and the synonym are normalized by the provided [`Tokenizer`],
+    /// it must be the one tokenizing the expanded text when it doesn't use the default settings.
+    pub fn insert_with<A: AsRef<[u8]>>(
+        &mut self,
+        tokenizer: &Tokenizer<A>,
+        source: &str,
+        synonym: &str,
+    ) -> &mut Self {
+        self.insert_words(words(tokenizer.tokenize(source)), words(tokenizer.tokenize(synonym)))
+    }
+
+    fn insert_words(&mut self, source: Vec<String>, synonym: Vec<String>) -> &mut Self {
+        if source.is_empty() || synonym.is_empty() {
+            return self;
+        }
+
+        self.max_source_len = self.max_source_len.max(source.len());
+        let synonyms = self.synonyms.entry(source).or_default();
+        if !synonyms.contains(&synonym) {
+            synonyms.push(synonym);
+        }
+        self
+    }
+
+    /// Returns the synonyms of the provided normalized words.
+    pub fn get(&self, words: &[String]) -> Option<&[Vec<String>]> {
+        self.synonyms.get(words).map(Vec::as_slice)
+    }
+
+    /// Returns true if no synonym has been added.
+    pub fn is_empty(&self) -> bool {
+        self.synonyms.is_empty()
+    }
+}
+
+/// Returns the normalized lemmas of the words of the provided tokens, without the subwords.
+fn words<'o>(tokens: impl Iterator<Item = Token<'o>>) -> Vec<String> {
+    tokens.filter(|t| !t.is_separator() && !t.is_subword).map(|t| t.lemma().to_string()).collect()
+}
+
+/// Iterator over normalized [`Token`]s followed by their synonyms.
+pub struct SynonymTokenIter<'o, 'al, 'sw, 'sy, A> {
+    token_iter: NormalizedTokenIter<'o, 'al, 'sw, A>,
+    synonyms: &'sy Synonyms,
+    /// tokens read from `token_iter` but not emitted yet.
+    lookahead: VecDeque<Token<'o>>,
+    /// number of tokens of `lookahead` to emit before the pending synonyms.
+    source_len: usize,
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<A> SynonymTokenIter<'_, '_, '_, '_, A> {
+    /// Returns the number of words of the longest synonym source.
+    pub(crate) fn max_source_len(&self) -> usize {
+        self.synonyms.max_source_len
+    }
+}
+
+impl<'o, A: AsRef<[u8]>> SynonymTokenIter<'o, '_, '_, '_, A> {
+    /// Reads the tokens following the first token of `lookahead`
+    /// until the longest source could be matched.
+    fn fill_lookahead(&mut self) {
+        let mut word_count =
+            self.lookahead.iter().filter(|t| !t.is_separator() && !t.is_subword).count();
+        while word_count < self.synonyms.max_source_len {
+            if self.lookahead.back().and_then(Token::separator_kind) == Some(SeparatorKind::Hard) {
+                break;
+            }
+            match self.token_iter.next() {
+                Some(token) => {
+                    word_count += !(token.is_separator() || token.is_subword) as usize;
+                    self.lookahead.push_back(token);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Returns the index of the last token of the longest source starting
+/// at the beginning of `tokens` with the corresponding synonyms.
+fn longest_source<'sy>(
+    tokens: &VecDeque<Token>,
+    synonyms: &'sy Synonyms,
+) -> Option<(usize, &'sy [Vec<String>])> {
+    let mut words = Vec::new();
+    let mut longest = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match token.separator_kind() {
+            Some(SeparatorKind::Hard) => break,
+            Some(SeparatorKind::Soft) => continue,
+            None if token.is_subword => continue,
+            None => {
+                words.push(token.lemma().to_string());
+                if let Some(synonyms) = synonyms.get(&words) {
+                    longest = Some((index, synonyms));
+                }
+            }
+        }
+    }
+
+    longest
+}
+
+impl<'o, A: AsRef<[u8]>> Iterator for SynonymTokenIter<'o, '_, '_, '_, A> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.source_len > 0 {
+            self.source_len -= 1;
+            return self.lookahead.pop_front();
+        }
+        if let Some(synonym) = self.pending.pop_front() {
+            return Some(synonym);
+        }
+
+        if self.lookahead.is_empty() {
+            self.lookahead.push_back(self.token_iter.next()?);
+        }
+        if self.lookahead[0].is_separator()
+            || self.lookahead[0].is_subword
+            || self.synonyms.is_empty()
+        {
+            return self.lookahead.pop_front();
+        }
+
+        self.fill_lookahead();
+        if let Some((last, synonyms)) = longest_source(&self.lookahead, self.synonyms) {
+            let first = &self.lookahead[0];
+            let last_token = &self.lookahead[last];
+            let words = synonyms.iter().flat_map(|synonym| synonym.iter().enumerate());
+            for (synonym_index, word) in words {
+                self.pending.push_back(Token {
+                    kind: TokenKind::Word,
+                    lemma: Cow::Owned(word.clone()),
+                    char_start: first.char_start,
+                    char_end: last_token.char_end,
+                    byte_start: first.byte_start,
+                    byte_end: last_token.byte_end,
+                    char_map: None,
+                    script: first.script,
+                    language: first.language,
+                    is_synonym: true,
+                    synonym_index,
+                    is_subword: false,
+                    alternatives: Vec::new(),
+                });
+            }
+            self.source_len = last + 1;
+            return self.next();
+        }
+
+        self.lookahead.pop_front()
+    }
+}
+
+impl<'o, 'al, 'sw, A> NormalizedTokenIter<'o, 'al, 'sw, A> {
+    /// Expands the normalized tokens with the provided [`Synonyms`],
+    /// the synonyms of a source are emitted right after its last token.
+    pub fn synonyms<'sy>(self, synonyms: &'sy Synonyms) -> SynonymTokenIter<'o, 'al, 'sw, 'sy, A> {
+        SynonymTokenIter {
+            token_iter: self,
+            synonyms,
+            lookahead: VecDeque::new(),
+            source_len: 0,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expand(text: &str, synonyms: &Synonyms) -> Vec<(String, usize, usize, bool)> {
+        text.tokenize()
+            .synonyms(synonyms)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma().to_string(), t.byte_start, t.byte_end, t.is_synonym()))
+            .collect()
+    }
+
+    #[test]
+    fn single_word_source() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("tv", "television").insert("TV", "telly");
+
+        assert_eq!(
+            expand("My TV broke", &synonyms),
+            [
+                ("my".to_string(), 0, 2, false),
+                ("tv".to_string(), 3, 5, false),
+                ("television".to_string(), 3, 5, true),
+                ("telly".to_string(), 3, 5, true),
+                ("broke".to_string(), 6, 11, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_word_source() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("new york", "nyc").insert("new york city", "big apple");
+
+        assert_eq!(
+            expand("I love New York City.", &synonyms),
+            [
+                ("i".to_string(), 0, 1, false),
+                ("love".to_string(), 2, 6, false),
+                ("new".to_string(), 7, 10, false),
+                ("york".to_string(), 11, 15, false),
+                ("city".to_string(), 16, 20, false),
+                ("big".to_string(), 7, 20, true),
+                ("apple".to_string(), 7, 20, true),
+            ]
+        );
+
+        assert_eq!(
+            expand("new york, new. york", &synonyms),
+            [
+                ("new".to_string(), 0, 3, false),
+                ("york".to_string(), 4, 8, false),
+                ("nyc".to_string(), 0, 8, true),
+                ("new".to_string(), 10, 13, false),
+                ("york".to_string(), 15, 19, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_word_synonyms_positions() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("nyc", "new york").insert("nyc", "big apple");
+
+        let positions: Vec<_> = "in nyc"
+            .tokenize()
+            .synonyms(&synonyms)
+            .with_positions(8)
+            .filter(|(_, t)| t.is_word())
+            .map(|(position, t)| (position, t.lemma().to_string()))
+            .collect();
+        // each synonym is a sequence of positions starting at the position of its source.
+        assert_eq!(
+            positions,
+            [
+                (0, "in".to_string()),
+                (1, "nyc".to_string()),
+                (1, "new".to_string()),
+                (2, "york".to_string()),
+                (1, "big".to_string()),
+                (2, "apple".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_tokenizer() {
+        let mut builder = crate::TokenizerBuilder::default();
+        builder.normalizer_option(crate::normalizer::NormalizerOption {
+            lowercase: false,
+            ..Default::default()
+        });
+        let tokenizer = builder.build();
+
+        // the default settings lowercase the source which can't match the text of the tokenizer anymore.
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("NYC", "New York");
+        assert!(tokenizer.tokenize("NYC").synonyms(&synonyms).all(|t| !t.is_synonym()));
+
+        let mut synonyms = Synonyms::new();
+        synonyms.insert_with(&tokenizer, "NYC", "New York");
+        let lemmas: Vec<_> = tokenizer.tokenize("NYC").synonyms(&synonyms).collect();
+        let lemmas: Vec<_> = lemmas.iter().map(|t| t.lemma()).collect();
+        assert_eq!(lemmas, ["NYC", "New", "York"]);
+    }
+
+    #[test]
+    fn subwords_are_ignored() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("arbeitsamt", "jobcenter").insert("arbeit amt", "office");
+
+        let word = |lemma: &str, is_subword| Token {
+            kind: TokenKind::Word,
+            lemma: Cow::Owned(lemma.to_string()),
+            is_subword,
+            ..Default::default()
+        };
+        let tokens =
+            VecDeque::from([word("arbeitsamt", false), word("arbeit", true), word("amt", true)]);
+        let (last, found) = longest_source(&tokens, &synonyms).unwrap();
+        assert_eq!((last, found), (0, &[vec!["jobcenter".to_string()]][..]));
+    }
+
+    #[test]
+    fn separators_are_kept() {
+        let mut synonyms = Synonyms::new();
+        synonyms.insert("nyc", "new york");
+
+        let lemmas: Vec<_> = "nyc, nyc".tokenize().synonyms(&synonyms).collect();
+        let lemmas: Vec<_> = lemmas.iter().map(|t| t.lemma()).collect();
+        assert_eq!(lemmas, ["nyc", "new", "york", ",", " ", "nyc", "new", "york"]);
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use serde::{Deserialize, Serialize};
+
 use crate::detection::{Language, Script};
 
 /// Define the kind of a [`TokenKind::Separator`].
@@ -7,14 +9,14 @@ use crate::detection::{Language, Script};
 /// A separator has two kinds:
 /// - `Hard`: Separate two tokens that are not in the same context (different phrases).
 /// - `Soft`: Separate two tokens that are in the same context (same phrase).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SeparatorKind {
     Hard,
     Soft,
 }
 
 /// Define the kind of a [`Token`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TokenKind {
     Word,
     /// the token is a stop word,
@@ -23,16 +25,11 @@ pub enum TokenKind {
     /// the token is a separator,
     /// meaning that it shouldn't be indexed but used to determine word proximity
     Separator(SeparatorKind),
+    #[default]
     Unknown,
 }
 
-impl Default for TokenKind {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Token<'o> {
     /// kind of the Token assigned by the classifier
     pub kind: TokenKind,
@@ -50,6 +47,20 @@ pub struct Token<'o> {
     pub script: Script,
     /// language of the Token
     pub language: Option<Language>,
+    /// true if the Token has been added by the synonym expansion,
+    /// a synonym has the same offsets as the tokens it has been expanded from
+    pub is_synonym: bool,
+    /// index of the word in the synonym the Token belongs to, the words of a multi-word synonym
+    /// get consecutive positions starting at the position of their source
+    pub synonym_index: usize,
+    /// true if the Token is a part of the word preceding it, like a part of a compound word or the stem of a prefixed word,
+    /// a subword has the offsets of the part in the original word
+    pub is_subword: bool,
+    /// other normalized forms of the Token produced by some normalizers, like the unstemmed lemma,
+    /// an alternative has the same offsets as the Token but is not covered by the `char_map`,
+    /// the [`Highlighter`](crate::highlight::Highlighter) and [`QueryTerm::matches`](crate::QueryTerm::matches)
+    /// match them like the lemma
+    pub alternatives: Vec<String>,
 }
 
 impl Token<'_> {
@@ -58,6 +69,12 @@ impl Token<'_> {
         self.lemma.as_ref()
     }
 
+    /// Returns an iterator over the normalized lemma followed by the [`alternatives`](Token::alternatives),
+    /// a word matches a query word if one of their lemmas is equal.
+    pub fn lemmas(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.lemma()).chain(self.alternatives.iter().map(String::as_str))
+    }
+
     /// Returns the length in bytes of the normalized lemma.
     pub fn byte_len(&self) -> usize {
         self.lemma.len()
@@ -78,6 +95,25 @@ impl Token<'_> {
         self.char_end - self.char_start
     }
 
+    /// Returns an owned version of the current token, cloning the lemma if it's borrowed.
+    pub fn into_owned(self) -> Token<'static> {
+        Token {
+            kind: self.kind,
+            lemma: Cow::Owned(self.lemma.into_owned()),
+            char_start: self.char_start,
+            char_end: self.char_end,
+            byte_start: self.byte_start,
+            byte_end: self.byte_end,
+            char_map: self.char_map,
+            script: self.script,
+            language: self.language,
+            is_synonym: self.is_synonym,
+            synonym_index: self.synonym_index,
+            is_subword: self.is_subword,
+            alternatives: self.alternatives,
+        }
+    }
+
     /// Returns the [`TokenKind`] of the current token.
     pub fn kind(&self) -> TokenKind {
         self.kind
@@ -95,9 +131,19 @@ impl Token<'_> {
         self.kind == TokenKind::StopWord
     }
 
+    /// Returns true if the current token has been added by the synonym expansion.
+    pub fn is_synonym(&self) -> bool {
+        self.is_synonym
+    }
+
+    /// Returns true if the current token is a part of the word preceding it.
+    pub fn is_subword(&self) -> bool {
+        self.is_subword
+    }
+
     /// Returns true if the current token is a separator.
     pub fn is_separator(&self) -> bool {
-        self.separator_kind().map_or(false, |_| true)
+        self.separator_kind().is_some()
     }
 
     /// Returns Some([`SeparatorKind`]) if the token is a separator and None if it's a word or a stop word.
@@ -161,3 +207,41 @@ impl Token<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TokenizerBuilder;
+
+    fn tokens() -> Vec<Token<'static>> {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+        tokenizer.tokenize("Thé quick, 東京!").map(Token::into_owned).collect()
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let tokens = tokens();
+        assert!(tokens.iter().any(|t| t.char_map.is_some()));
+
+        let json = serde_json::to_string(&tokens).unwrap();
+        let deserialized: Vec<Token> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, tokens);
+
+        let value = serde_json::to_value(&tokens[0]).unwrap();
+        assert_eq!(value["lemma"], "the");
+        assert_eq!(value["kind"], "Word");
+        assert_eq!(value["script"], "Latin");
+        assert_eq!(value["char_map"], serde_json::json!([[1, 1], [1, 1], [2, 1]]));
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let tokens = tokens();
+
+        let bytes = bincode::serialize(&tokens).unwrap();
+        let deserialized: Vec<Token> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, tokens);
+    }
+}
@@ -1,10 +1,16 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use fst::Set;
 
+use crate::classifier::SeparatorPolicy;
 use crate::detection::{Language, Script};
-use crate::normalizer::{NormalizedTokenIter, NormalizerOption};
-use crate::segmenter::{Segment, SegmentedStrIter, SegmentedTokenIter};
+use crate::normalizer::{NormalizedTokenIter, NormalizerOption, NormalizerPipeline, NORMALIZERS};
+use crate::segmenter::{
+    ProtectedTerms, Segment, SegmentedStrIter, SegmentedTokenIter, Segmenter, SegmenterMap,
+    SegmenterOption, SharedSegmenterOption, SHARED_SEGMENTERS,
+};
+use crate::shared::Shared;
 use crate::Token;
 
 /// Iterator over tuples of [`&str`] (part of the original text) and [`Token`].
@@ -51,7 +57,7 @@ pub trait Tokenize<'o, A: AsRef<[u8]>> {
     /// assert_eq!(lemma, "quick");
     /// assert_eq!(kind, TokenKind::Word);
     /// ```
-    fn tokenize(&self) -> NormalizedTokenIter<A>;
+    fn tokenize(&self) -> NormalizedTokenIter<'_, '_, '_, A>;
 
     /// Same as [`tokenize`] but attaches each [`Token`] to its corresponding portion of the original text.
     ///
@@ -79,15 +85,20 @@ pub trait Tokenize<'o, A: AsRef<[u8]>> {
     /// assert_eq!(lemma, "quick");
     /// assert_eq!(kind, TokenKind::Word);
     /// ```
-    fn reconstruct(&self) -> ReconstructedTokenIter<A>;
+    fn reconstruct(&self) -> ReconstructedTokenIter<'_, '_, '_, A>;
 }
 
 impl Tokenize<'_, Vec<u8>> for &str {
-    fn tokenize(&self) -> NormalizedTokenIter<Vec<u8>> {
-        self.segment().classify().normalize(NormalizerOption::default())
+    fn tokenize(&self) -> NormalizedTokenIter<'_, '_, '_, Vec<u8>> {
+        let normalizer_option = NormalizerOption::default();
+        let option = SegmenterOption {
+            detect_language: NORMALIZERS.needs_language(normalizer_option),
+            ..Default::default()
+        };
+        self.segment_with_option(option).classify().normalize(normalizer_option)
     }
 
-    fn reconstruct(&self) -> ReconstructedTokenIter<Vec<u8>> {
+    fn reconstruct(&self) -> ReconstructedTokenIter<'_, '_, '_, Vec<u8>> {
         ReconstructedTokenIter { original: self, token_iter: self.tokenize() }
     }
 }
@@ -95,9 +106,17 @@ impl Tokenize<'_, Vec<u8>> for &str {
 /// Structure used to tokenize a text with custom configurations.
 ///
 /// See [`TokenizerBuilder`] to know how to build a [`Tokenizer`].
+///
+/// A `Tokenizer` built only with shared settings (see [`TokenizerBuilder::shared_stop_words`]
+/// and [`TokenizerBuilder::shared_allow_list`]) is `'static`,
+/// it can then be cloned cheaply and sent to other threads.
 pub struct Tokenizer<'al, 'sw, A> {
-    allow_list: Option<&'al HashMap<Script, Vec<Language>>>,
-    stop_words: Option<&'sw Set<A>>,
+    allow_list: Option<Shared<'al, HashMap<Script, Vec<Language>>>>,
+    segmenters: Option<Arc<SegmenterMap>>,
+    stop_words: Option<Shared<'sw, Set<A>>>,
+    separator_policy: Option<Arc<SeparatorPolicy>>,
+    protected_terms: Option<Arc<ProtectedTerms>>,
+    normalizer_pipeline: Option<Arc<NormalizerPipeline>>,
     normalizer_option: NormalizerOption,
 }
 
@@ -107,10 +126,12 @@ impl<'al, 'sw, A: AsRef<[u8]>> Tokenizer<'al, 'sw, A> {
     /// The provided text is segmented creating tokens,
     /// then tokens are normalized and classified.
     pub fn tokenize<'o>(&self, original: &'o str) -> NormalizedTokenIter<'o, 'al, 'sw, A> {
-        original
-            .segment_with_allowlist(self.allow_list)
-            .classify_with_stop_words(self.stop_words)
-            .normalize(self.normalizer_option)
+        self.segment(original)
+            .classify_with_shared_options(
+                self.stop_words.clone(),
+                self.separator_policy.clone().map(Shared::Arc),
+            )
+            .normalize_with_shared_pipeline(self.normalizer_pipeline(), self.normalizer_option)
     }
 
     /// Same as [`tokenize`] but attaches each [`Token`] to its corresponding portion of the original text.
@@ -120,12 +141,43 @@ impl<'al, 'sw, A: AsRef<[u8]>> Tokenizer<'al, 'sw, A> {
 
     /// Segments the provided text creating an Iterator over [`Token`].
     pub fn segment<'o>(&self, original: &'o str) -> SegmentedTokenIter<'o, 'al> {
-        original.segment_with_allowlist(self.allow_list)
+        self.segment_str(original).into()
     }
 
     /// Segments the provided text creating an Iterator over `&str`.
     pub fn segment_str<'o>(&self, original: &'o str) -> SegmentedStrIter<'o, 'al> {
-        original.segment_str_with_allowlist(self.allow_list)
+        SegmentedStrIter::with_shared_option(original, self.segmenter_option())
+    }
+
+    pub(crate) fn segmenter_option(&self) -> SharedSegmenterOption<'al> {
+        SharedSegmenterOption {
+            allow_list: self.allow_list.clone(),
+            segmenters: self
+                .segmenters
+                .clone()
+                .map_or(Shared::Borrowed(&*SHARED_SEGMENTERS), Shared::Arc),
+            separator_policy: self.separator_policy.clone().map(Shared::Arc),
+            protected_terms: self.protected_terms.clone().map(Shared::Arc),
+            detect_language: self.normalizer_pipeline().needs_language(self.normalizer_option),
+        }
+    }
+
+    fn normalizer_pipeline(&self) -> Shared<'al, NormalizerPipeline> {
+        self.normalizer_pipeline.clone().map_or(Shared::Borrowed(&*NORMALIZERS), Shared::Arc)
+    }
+}
+
+impl<A> Clone for Tokenizer<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Self {
+            allow_list: self.allow_list.clone(),
+            segmenters: self.segmenters.clone(),
+            stop_words: self.stop_words.clone(),
+            separator_policy: self.separator_policy.clone(),
+            protected_terms: self.protected_terms.clone(),
+            normalizer_pipeline: self.normalizer_pipeline.clone(),
+            normalizer_option: self.normalizer_option,
+        }
     }
 }
 
@@ -157,8 +209,12 @@ impl<'al, 'sw, A: AsRef<[u8]>> Tokenizer<'al, 'sw, A> {
 /// ```
 ///
 pub struct TokenizerBuilder<'al, 'sw, A> {
-    allow_list: Option<&'al HashMap<Script, Vec<Language>>>,
-    stop_words: Option<&'sw Set<A>>,
+    allow_list: Option<Shared<'al, HashMap<Script, Vec<Language>>>>,
+    segmenters: HashMap<(Script, Language), Arc<dyn Segmenter>>,
+    stop_words: Option<Shared<'sw, Set<A>>>,
+    separator_policy: Option<Arc<SeparatorPolicy>>,
+    protected_terms: Option<Arc<ProtectedTerms>>,
+    normalizer_pipeline: Option<Arc<NormalizerPipeline>>,
     normalizer_option: NormalizerOption,
 }
 
@@ -167,7 +223,15 @@ impl<'al, 'sw, A> TokenizerBuilder<'al, 'sw, A> {
     ///
     /// if you don't plan to set stop_words, prefer use [`TokenizerBuilder::default`]
     pub fn new() -> TokenizerBuilder<'al, 'sw, A> {
-        Self { stop_words: None, normalizer_option: NormalizerOption::default(), allow_list: None }
+        Self {
+            stop_words: None,
+            separator_policy: None,
+            protected_terms: None,
+            normalizer_pipeline: None,
+            normalizer_option: NormalizerOption::default(),
+            allow_list: None,
+            segmenters: SegmenterMap::new(),
+        }
     }
 }
 
@@ -178,7 +242,75 @@ impl<'al, 'sw, A> TokenizerBuilder<'al, 'sw, A> {
     ///
     /// * `stop_words` - a `Set` of the words to classify as stop words.
     pub fn stop_words(&mut self, stop_words: &'sw Set<A>) -> &mut Self {
-        self.stop_words = Some(stop_words);
+        self.stop_words = Some(Shared::Borrowed(stop_words));
+        self
+    }
+
+    /// Same as [`stop_words`](Self::stop_words) but the `Set` is shared with the built `Tokenizer`s instead of being borrowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `stop_words` - a shared `Set` of the words to classify as stop words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// use fst::Set;
+    ///
+    /// use charabia::{Tokenizer, TokenizerBuilder};
+    ///
+    /// let stop_words = Arc::new(Set::from_iter(["the"].iter()).unwrap());
+    ///
+    /// let mut builder = TokenizerBuilder::new();
+    /// builder.shared_stop_words(stop_words);
+    /// let tokenizer: Tokenizer<'static, 'static, Vec<u8>> = builder.build();
+    ///
+    /// let handle = thread::spawn(move || {
+    ///     tokenizer.tokenize("the quick fox").filter(|t| t.is_stopword()).count()
+    /// });
+    ///
+    /// assert_eq!(handle.join().unwrap(), 1);
+    /// ```
+    pub fn shared_stop_words(&mut self, stop_words: Arc<Set<A>>) -> &mut Self {
+        self.stop_words = Some(Shared::Arc(stop_words));
+        self
+    }
+
+    /// Configure the [`SeparatorPolicy`] used to classify the separators.
+    ///
+    /// The characters marked as word characters by the policy are never split from the adjacent words.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator_policy` - the `SeparatorPolicy` overriding the default separators.
+    pub fn separator_policy(&mut self, separator_policy: SeparatorPolicy) -> &mut Self {
+        self.separator_policy = Some(Arc::new(separator_policy));
+        self
+    }
+
+    /// Configure the terms that must never be split by the segmenters,
+    /// each occurrence of a protected term is classified as a `TokenKind::Word`.
+    ///
+    /// # Arguments
+    ///
+    /// * `protected_terms` - the `ProtectedTerms` dictionary.
+    pub fn protected_terms(&mut self, protected_terms: ProtectedTerms) -> &mut Self {
+        self.protected_terms = Some(Arc::new(protected_terms));
+        self
+    }
+
+    /// Configure the [`NormalizerPipeline`] used to normalize the `Token`s.
+    ///
+    /// By default, the pipeline containing all the normalizers of the library is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline` - the `NormalizerPipeline` to use.
+    pub fn normalizer_pipeline(&mut self, pipeline: NormalizerPipeline) -> &mut Self {
+        self.normalizer_pipeline = Some(Arc::new(pipeline));
         self
     }
 
@@ -192,22 +324,101 @@ impl<'al, 'sw, A> TokenizerBuilder<'al, 'sw, A> {
         self
     }
 
+    /// Configure the [`NormalizerOption`] used to normalize the `Token`s,
+    /// this allows to disable some of the built-in normalizations.
+    ///
+    /// Note that it overrides the value previously set with [`create_char_map`](Self::create_char_map).
+    ///
+    /// # Arguments
+    ///
+    /// * `normalizer_option` - the `NormalizerOption` to use.
+    pub fn normalizer_option(&mut self, normalizer_option: NormalizerOption) -> &mut Self {
+        self.normalizer_option = normalizer_option;
+        self
+    }
+
     /// Configure which languages can be used for which script
     ///
     /// # Arguments
     ///
     /// * `allow_list` - a `HashMap` of the selection of languages associated with a script to limit during autodetection.
     pub fn allow_list(&mut self, allow_list: &'al HashMap<Script, Vec<Language>>) -> &mut Self {
-        self.allow_list = Some(allow_list);
+        self.allow_list = Some(Shared::Borrowed(allow_list));
+        self
+    }
+
+    /// Same as [`allow_list`](Self::allow_list) but the `HashMap` is shared with the built `Tokenizer`s instead of being borrowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_list` - a shared `HashMap` of the selection of languages associated with a script to limit during autodetection.
+    pub fn shared_allow_list(
+        &mut self,
+        allow_list: Arc<HashMap<Script, Vec<Language>>>,
+    ) -> &mut Self {
+        self.allow_list = Some(Shared::Arc(allow_list));
+        self
+    }
+
+    /// Add or replace the [`Segmenter`] used for a [`Script`] and a [`Language`].
+    ///
+    /// The segmenters provided here are merged into the default segmenters list ([`SEGMENTERS`](crate::segmenter::SEGMENTERS)),
+    /// a segmenter assigned to `Language::Other` is used for any `Language` that uses the assigned `Script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - the `Script` the segmenter is specialized for.
+    /// * `language` - the `Language` the segmenter is specialized for.
+    /// * `segmenter` - the `Segmenter` to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::segmenter::Segmenter;
+    /// use charabia::{Language, Script, TokenizerBuilder};
+    ///
+    /// struct WhitespaceSegmenter;
+    ///
+    /// impl Segmenter for WhitespaceSegmenter {
+    ///     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+    ///         Box::new(s.split_inclusive(' '))
+    ///     }
+    /// }
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.segmenter(Script::Latin, Language::Other, Box::new(WhitespaceSegmenter));
+    /// let tokenizer = builder.build();
+    ///
+    /// let mut segments = tokenizer.segment_str("can't stop");
+    /// assert_eq!(segments.next(), Some("can't "));
+    /// assert_eq!(segments.next(), Some("stop"));
+    /// ```
+    pub fn segmenter(
+        &mut self,
+        script: Script,
+        language: Language,
+        segmenter: Box<dyn Segmenter>,
+    ) -> &mut Self {
+        self.segmenters.insert((script, language), Arc::from(segmenter));
         self
     }
 
     /// Build the configurated `Tokenizer`.
     pub fn build(&self) -> Tokenizer<'al, 'sw, A> {
+        let segmenters = (!self.segmenters.is_empty()).then(|| {
+            let mut segmenters = SHARED_SEGMENTERS.clone();
+            segmenters.extend(self.segmenters.iter().map(|(k, v)| (*k, v.clone())));
+            Arc::new(segmenters)
+        });
+
         Tokenizer {
-            stop_words: self.stop_words,
+            stop_words: self.stop_words.clone(),
+            separator_policy: self.separator_policy.clone(),
+            protected_terms: self.protected_terms.clone(),
+            normalizer_pipeline: self.normalizer_pipeline.clone(),
             normalizer_option: self.normalizer_option,
-            allow_list: self.allow_list,
+            allow_list: self.allow_list.clone(),
+            segmenters,
         }
     }
 }
@@ -220,9 +431,15 @@ impl Default for TokenizerBuilder<'_, '_, Vec<u8>> {
 
 #[cfg(test)]
 mod test {
+    use std::sync::Arc;
+    use std::thread;
+
     use fst::Set;
 
-    use crate::tokenizer::{Tokenize, TokenizerBuilder};
+    use crate::classifier::SeparatorPolicy;
+    use crate::segmenter::{ProtectedTerms, Segment, Segmenter};
+    use crate::tokenizer::{Tokenize, Tokenizer, TokenizerBuilder};
+    use crate::{Language, Script, TokenKind};
 
     #[test]
     fn check_lifetimes() {
@@ -252,5 +469,142 @@ mod test {
             tokens
         };
         assert_eq!(tokens.iter().last().map(|t| t.lemma()), Some("."));
+
+        // the iterators don't borrow the tokenizer.
+        let stop_words: Set<Vec<u8>> = Set::from_iter(["to"].iter()).unwrap();
+        let (tokens, segments) = {
+            let tokenizer = TokenizerBuilder::new()
+                .stop_words(&stop_words)
+                .protected_terms(ProtectedTerms::from_terms(["you."], false).unwrap())
+                .build();
+            (tokenizer.tokenize(text), tokenizer.segment_str(text))
+        };
+        assert_eq!(tokens.last().map(|t| t.lemma().to_string()), Some("you.".to_string()));
+        assert_eq!(segments.last(), Some("you."));
+    }
+
+    #[test]
+    fn shared_tokenizer() {
+        let text = "Hello world! Pleased to see you.";
+
+        let stop_words: Set<Vec<u8>> = Set::from_iter(["to"].iter()).unwrap();
+        let allow_list = [(Script::Latin, vec![Language::Eng])].into_iter().collect();
+
+        let tokenizer: Tokenizer<'static, 'static, Vec<u8>> = TokenizerBuilder::new()
+            .shared_stop_words(Arc::new(stop_words))
+            .shared_allow_list(Arc::new(allow_list))
+            .build();
+        let tokenizer = Arc::new(tokenizer);
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let tokenizer = tokenizer.clone();
+                thread::spawn(move || {
+                    tokenizer.tokenize(text).map(|t| (t.lemma().to_string(), t.kind)).collect()
+                })
+            })
+            .collect();
+
+        let expected: Vec<_> =
+            tokenizer.tokenize(text).map(|t| (t.lemma().to_string(), t.kind)).collect();
+        assert!(expected.iter().any(|(lemma, _)| lemma == "to"));
+        assert_eq!(expected.iter().filter(|(_, kind)| *kind == TokenKind::StopWord).count(), 1);
+        for handle in handles {
+            let tokens: Vec<_> = handle.join().unwrap();
+            assert_eq!(tokens, expected);
+        }
+
+        let cloned = (*tokenizer).clone();
+        assert_eq!(cloned.tokenize(text).count(), expected.len());
+    }
+
+    #[test]
+    fn custom_segmenter() {
+        struct WhitespaceSegmenter;
+
+        impl Segmenter for WhitespaceSegmenter {
+            fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+                Box::new(s.split_inclusive(' '))
+            }
+        }
+
+        let text = "Hello world! ภาษาไทยง่ายนิดเดียว";
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(Script::Latin, Language::Other, Box::new(WhitespaceSegmenter));
+        let tokenizer = builder.build();
+
+        let segments: Vec<_> = tokenizer.segment_str(text).collect();
+        // Latin is segmented by the custom segmenter, Thai keeps the default one.
+        assert_eq!(segments, ["Hello ", "world! ", "ภาษาไทย", "ง่าย", "นิดเดียว"]);
+
+        // the global segmentation is not impacted.
+        let segments: Vec<_> = text.segment_str().collect();
+        assert_eq!(segments[..4], ["Hello", " ", "world", "!"]);
+    }
+
+    #[test]
+    fn separator_policy() {
+        let text = "C++ and C#, TCP/IP + UDP";
+
+        let mut policy = SeparatorPolicy::new();
+        policy.word_char('+').word_char('#').word_char('/');
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_policy(policy);
+        let tokenizer = builder.build();
+
+        let segments: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(
+            segments,
+            ["C++", " ", "and", " ", "C#", ",", " ", "TCP/IP", " ", "+", " ", "UDP"]
+        );
+
+        let token = tokenizer.tokenize(text).find(|t| t.lemma() == "tcp/ip").unwrap();
+        assert!(token.is_word());
+        assert_eq!((token.byte_start, token.byte_end), (12, 18));
+        assert!(tokenizer.tokenize(text).any(|t| t.lemma() == "+" && t.is_word()));
+
+        // the default segmentation is not impacted.
+        let segments: Vec<_> = text.segment_str().take(3).collect();
+        assert_eq!(segments, ["C", "+", "+"]);
+    }
+
+    #[test]
+    fn protected_terms() {
+        let text = "I use C++ and .NET over Wi-Fi, ABC++ is not AT&T's node.js.";
+
+        let terms =
+            ProtectedTerms::from_terms(["C++", "Wi-Fi", "AT&T", ".NET", "node.js"], true).unwrap();
+        let mut builder = TokenizerBuilder::default();
+        builder.protected_terms(terms);
+        let tokenizer = builder.build();
+
+        let words: Vec<_> = tokenizer
+            .tokenize(text)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma().to_string(), t.byte_start, t.byte_end))
+            .collect();
+        assert_eq!(
+            words,
+            [
+                ("i".to_string(), 0, 1),
+                ("use".to_string(), 2, 5),
+                ("c++".to_string(), 6, 9),
+                ("and".to_string(), 10, 13),
+                (".net".to_string(), 14, 18),
+                ("over".to_string(), 19, 23),
+                ("wi-fi".to_string(), 24, 29),
+                ("abc".to_string(), 31, 34),
+                ("is".to_string(), 37, 39),
+                ("not".to_string(), 40, 43),
+                ("at&t".to_string(), 44, 48),
+                ("s".to_string(), 49, 50),
+                ("node.js".to_string(), 51, 58),
+            ]
+        );
+
+        // the default segmentation is not impacted.
+        let segments: Vec<_> = text.segment_str().skip(4).take(3).collect();
+        assert_eq!(segments, ["C", "+", "+"]);
     }
 }